@@ -0,0 +1,165 @@
+use crate::{
+    hdk,
+    hdi_extensions,
+};
+
+use std::{
+    collections::BTreeMap,
+    io::Write,
+};
+use hdk::prelude::*;
+use hdi_extensions::{
+    guest_error,
+};
+use flate2::{
+    Compression,
+    write::GzEncoder,
+};
+pub use devhub_types::{
+    decompress_memory_bytes,
+    gunzip,
+};
+use serde_bytes::ByteBuf;
+
+
+pub const BUNDLE_MANIFEST_VERSION : &str = "1";
+
+pub type BundleResources = BTreeMap<String, Vec<u8>>;
+
+
+/// The serialized layout of a Holochain bundle (`.dna`, `.happ`, `.webhapp`)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct BundleData {
+    manifest: rmpv::Value,
+    resources: BTreeMap<String, ByteBuf>,
+}
+
+
+pub fn gzip(bytes: &[u8]) -> ExternResult<Vec<u8>> {
+    let mut encoder = GzEncoder::new( Vec::new(), Compression::default() );
+
+    encoder.write_all( bytes )
+        .map_err( |err| guest_error!(format!("Failed to compress bytes: {:?}", err )) )?;
+
+    encoder.finish()
+        .map_err( |err| guest_error!(format!("Failed to compress bytes: {:?}", err )) )
+}
+
+
+/// Assemble bundle bytes (gzipped msgpack) from a stored manifest and its resources
+///
+/// The stored manifests do not include `manifest_version`, so it is added in front of the other
+/// fields unless it is already present.
+pub fn encode_bundle(manifest: &rmpv::Value, resources: BundleResources) -> ExternResult<Vec<u8>> {
+    let manifest = match manifest {
+        rmpv::Value::Map(fields) => {
+            let has_version = fields.iter()
+                .any( |(key, _)| key.as_str() == Some("manifest_version") );
+            let mut versioned = vec![];
+
+            if !has_version {
+                versioned.push((
+                    rmpv::Value::from("manifest_version"),
+                    rmpv::Value::from( BUNDLE_MANIFEST_VERSION ),
+                ));
+            }
+
+            versioned.extend( fields.iter().cloned() );
+
+            rmpv::Value::Map( versioned )
+        },
+        _ => return Err(guest_error!(format!(
+            "Bundle manifest must be a map"
+        ))),
+    };
+
+    let bundle = BundleData {
+        manifest,
+        resources: resources.into_iter()
+            .map( |(path, bytes)| ( path, ByteBuf::from( bytes ) ) )
+            .collect(),
+    };
+    let packed = rmp_serde::encode::to_vec_named( &bundle )
+        .map_err( |err| guest_error!(format!("Failed to serialize bundle: {:?}", err )) )?;
+
+    gzip( &packed )
+}
+
+
+/// Unpack bundle bytes (gzipped msgpack) into the manifest and its resources
+///
+/// `manifest_version` is removed from the manifest so that it matches the stored form used by
+/// [`encode_bundle`].
+pub fn decode_bundle(bytes: &[u8]) -> ExternResult<(rmpv::Value, BundleResources)> {
+    let packed = gunzip( bytes )?;
+    let bundle : BundleData = rmp_serde::from_slice( &packed )
+        .map_err( |err| guest_error!(format!("Failed to deserialize bundle: {:?}", err )) )?;
+
+    let manifest = match bundle.manifest {
+        rmpv::Value::Map(fields) => rmpv::Value::Map(
+            fields.into_iter()
+                .filter( |(key, _)| key.as_str() != Some("manifest_version") )
+                .collect()
+        ),
+        _ => return Err(guest_error!(format!(
+            "Bundle manifest must be a map"
+        ))),
+    };
+    let resources = bundle.resources.into_iter()
+        .map( |(path, bytes)| ( path, bytes.into_vec() ) )
+        .collect();
+
+    Ok(( manifest, resources ))
+}
+
+
+/// Check if a manifest map has a top-level field
+pub fn manifest_has_field(manifest: &rmpv::Value, field: &str) -> bool {
+    match manifest {
+        rmpv::Value::Map(fields) => fields.iter()
+            .any( |(key, _)| key.as_str() == Some(field) ),
+        _ => false,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> rmpv::Value {
+        rmpv::Value::Map(vec![
+            ( rmpv::Value::from("name"), rmpv::Value::from("example") ),
+            ( rmpv::Value::from("integrity"), rmpv::Value::Map(vec![]) ),
+        ])
+    }
+
+    #[test]
+    fn bundle_round_trip() {
+        let resources = BundleResources::from([
+            ( "zome.wasm".to_string(), vec![ 0, 97, 115, 109 ] ),
+            ( "empty.wasm".to_string(), vec![] ),
+        ]);
+
+        let bytes = encode_bundle( &manifest(), resources.clone() ).unwrap();
+        let (decoded_manifest, decoded_resources) = decode_bundle( &bytes ).unwrap();
+
+        assert_eq!( decoded_manifest, manifest() );
+        assert_eq!( decoded_resources, resources );
+    }
+
+    #[test]
+    fn encoded_manifest_is_versioned() {
+        let bytes = encode_bundle( &manifest(), BundleResources::new() ).unwrap();
+        let bundle : BundleData = rmp_serde::from_slice( &gunzip( &bytes ).unwrap() ).unwrap();
+
+        assert!( manifest_has_field( &bundle.manifest, "manifest_version" ) );
+        assert!( !manifest_has_field( &manifest(), "manifest_version" ) );
+    }
+
+    #[test]
+    fn bundle_manifest_must_be_a_map() {
+        assert!( encode_bundle( &rmpv::Value::from("manifest"), BundleResources::new() ).is_err() );
+        assert!( decode_bundle( b"not a bundle" ).is_err() );
+    }
+}
@@ -1,4 +1,10 @@
+mod bundle;
 mod link_base;
+mod package_base;
+mod name_index;
+mod page;
+mod review;
+mod provenance;
 
 pub use hdk_extensions::hdi;
 pub use hdk_extensions::holo_hash;
@@ -6,12 +12,23 @@ pub use hdk_extensions::hdk;
 pub use hdk_extensions::hdi_extensions;
 pub use hdk_extensions;
 pub use hc_crud;
+pub use devhub_types;
+pub use bundle::*;
 pub use link_base::*;
+pub use package_base::*;
+pub use name_index::*;
+pub use page::*;
+pub use review::*;
+pub use provenance::*;
 
 use hdi_extensions::{
     guest_error,
 };
 use hdk::prelude::*;
+use devhub_types::{
+    StorageQuota,
+    DevHubProperties,
+};
 
 
 
@@ -21,6 +38,60 @@ pub fn timestamp() -> ExternResult<u64> {
 }
 
 
+/// The sizes of an agent's uploads of 1 entry type and the quota they are checked against
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StorageUsage {
+    pub agent: AgentPubKey,
+    pub entry_count: u64,
+    pub total_file_size: u64,
+    pub quota: StorageQuota,
+}
+
+
+/// Sum the sizes of every entry of `entry_type` that an agent has created
+///
+/// Counts the same entries as `devhub_types::check_storage_quota` so that the result shows how
+/// much of the quota remains.
+pub fn get_agent_storage_usage<F>(
+    agent: AgentPubKey,
+    entry_type: EntryType,
+    size_of: F,
+) -> ExternResult<StorageUsage>
+where
+    F: Fn(Entry) -> ExternResult<u64>,
+{
+    let activity = get_agent_activity(
+        agent.clone(),
+        ChainQueryFilter::new()
+            .entry_type( entry_type )
+            .action_type( ActionType::Create ),
+        ActivityRequest::Full,
+    )?;
+
+    let mut entry_count = 0;
+    let mut total_file_size : u64 = 0;
+
+    for (_, addr) in activity.valid_activity {
+        let record = get( addr.clone(), GetOptions::default() )?
+            .ok_or(guest_error!(format!("Record not found: {}", addr )))?;
+        let entry = record.entry().as_option()
+            .ok_or(guest_error!(format!("Record ({}) does not have an entry", addr )))?;
+
+        entry_count += 1;
+        total_file_size = total_file_size.saturating_add( size_of( entry.to_owned() )? );
+    }
+
+    Ok(
+        StorageUsage {
+            agent,
+            entry_count,
+            total_file_size,
+            quota: DevHubProperties::get()?.storage_quota,
+        }
+    )
+}
+
+
 pub struct PathInput(pub Vec<Component>);
 
 impl From<Vec<Component>> for PathInput {
@@ -1,6 +1,10 @@
 use crate::{
     hdk,
     hdk_extensions,
+    Page,
+    PageQuery,
+    SortOrder,
+    paginate_links,
 };
 
 use hdk::prelude::*;
@@ -64,6 +68,42 @@ where
         )
     }
 
+    /// Get the page of links described by `query`
+    ///
+    /// The cursor bounds the `get_links` query itself so that links before it (or after it when
+    /// descending) are never fetched.  The bound is widened by 1 microsecond because links that
+    /// share the cursor's timestamp are ordered by their create link hash in [`paginate_links`].
+    ///
+    /// `get_links` has no limit, so every link past the cursor is still fetched and sorted before
+    /// the page is sliced; the cost of a page grows with the number of links on the base.
+    pub fn get_links_page(&self, tag: Option<LinkTag>, query: &PageQuery) ->
+        ExternResult<Page<Link>>
+    {
+        Ok( paginate_links( self.get_links_from_cursor( tag, query )?, query ) )
+    }
+
+    /// Get the links on the `query` cursor's side of the ordering (not yet limited)
+    pub fn get_links_from_cursor(&self, tag: Option<LinkTag>, query: &PageQuery) ->
+        ExternResult<Vec<Link>>
+    {
+        let mut input = GetLinksInputBuilder::try_new( self.hash(), self.link_type() )?;
+
+        if let Some(tag) = tag {
+            input = input.tag_prefix( tag );
+        }
+
+        if let Some(cursor) = &query.cursor {
+            let micros = cursor.timestamp.as_micros();
+
+            input = match query.sort {
+                SortOrder::Ascending => input.after( Timestamp::from_micros( micros - 1 ) ),
+                SortOrder::Descending => input.before( Timestamp::from_micros( micros + 1 ) ),
+            };
+        }
+
+        get_links( input.build() )
+    }
+
     pub fn create_link<T>(
         &self,
         target: &T,
@@ -147,9 +187,57 @@ where
 
         Ok( deleted_links )
     }
+
+    /// Delete links to the target regardless of who created them (eg. for group maintained
+    /// content where any contributor may have made the link)
+    pub fn delete_all_links_to_target<T>(
+        &self,
+        target: &T,
+        tag: Option<LinkTag>,
+    ) -> ExternResult<Vec<ActionHash>>
+    where
+        T: Into<AnyLinkableHash> + Clone,
+    {
+        let target : AnyLinkableHash = target.to_owned().into();
+        let mut deleted_links = vec![];
+
+        for link in self.get_links( tag.clone() )? {
+            if link.target == target
+                && ( tag.is_none() || Some(link.tag) == tag )
+            {
+                debug!("Deleting link ({}): {} => {}", link.create_link_hash, self.hash(), target );
+                let delete_action = delete_link( link.create_link_hash )?;
+                deleted_links.push( delete_action );
+            }
+        }
+
+        Ok( deleted_links )
+    }
 }
 
 
+
+/// Get 1 page from the combined links of several bases (eg. an active and a deprecated anchor)
+///
+/// The cursor ordering is global, so a cursor from any page can be used with the same bases.
+pub fn get_links_page_from_bases<LT>(
+    bases: &[&LinkBase<LT>],
+    tag: Option<LinkTag>,
+    query: &PageQuery,
+) -> ExternResult<Page<Link>>
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    let mut links = vec![];
+
+    for base in bases {
+        links.extend( base.get_links_from_cursor( tag.clone(), query )? );
+    }
+
+    Ok( paginate_links( links, query ) )
+}
+
 pub fn create_link_input<B,LT,T>(
     base: &B,
     link_type: &LT,
@@ -0,0 +1,195 @@
+use crate::{
+    hdk,
+    hdi_extensions,
+    devhub_types,
+    LinkBase,
+};
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+use hdk::prelude::*;
+use hdi_extensions::{
+    guest_error,
+};
+use devhub_types::{
+    name_index_path,
+};
+pub use devhub_types::{
+    NAME_INDEX_ROOT,
+    NAME_SHARD_LENGTH,
+    normalize_name,
+    name_shard,
+    name_trigrams,
+    name_prefix_trigram,
+};
+
+
+/// Similarity (Sørensen–Dice over trigrams) that a name must reach to be a fuzzy match
+pub const FUZZY_MATCH_THRESHOLD : f64 = 0.4;
+
+
+/// Sørensen–Dice coefficient of the trigram sets of 2 names
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let a_grams = name_trigrams( a );
+    let b_grams = name_trigrams( b );
+    let shared = a_grams.intersection( &b_grams ).count();
+
+    ( 2 * shared ) as f64 / ( a_grams.len() + b_grams.len() ) as f64
+}
+
+
+/// A trigram index over names
+///
+/// Each target is linked from `names.<shard>.<trigram>` for every trigram of its padded name (see
+/// [`name_shard`] and [`name_trigrams`]), with the normalized name as the link tag.  Anchors are
+/// sharded by the name's first characters and by trigram so no single anchor has to hold every
+/// name, and lookups only read a few anchors and filter by tag.  Because the shard comes from the
+/// start of the name, searches only find names that start with the same characters as the query.
+pub struct NameIndex<LT>(pub LT)
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+;
+
+impl<LT> NameIndex<LT>
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub fn new(link_type: LT) -> Self {
+        Self( link_type )
+    }
+
+    pub fn link_type(&self) -> LT {
+        self.0
+    }
+
+    pub fn anchor(&self, shard: &str, gram: &str) -> ExternResult<LinkBase<LT>> {
+        Ok( LinkBase::new( name_index_path( shard, gram ).path_entry_hash()?, self.link_type() ) )
+    }
+
+    pub fn anchors(&self, name: &str) -> ExternResult<Vec<LinkBase<LT>>> {
+        let shard = name_shard( name );
+
+        name_trigrams( name ).iter()
+            .map( |gram| self.anchor( &shard, gram ) )
+            .collect()
+    }
+
+    pub fn add<T>(&self, name: &str, target: &T) -> ExternResult<()>
+    where
+        T: Into<AnyLinkableHash> + Clone,
+    {
+        let tag = normalize_name( name ).as_bytes().to_vec();
+
+        for anchor in self.anchors( name )? {
+            anchor.create_link_if_not_exists( target, tag.clone() )?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove the links that this agent created for the name
+    pub fn remove<T>(&self, name: &str, target: &T) -> ExternResult<Vec<ActionHash>>
+    where
+        T: Into<AnyLinkableHash> + Clone,
+    {
+        let mut deleted_links = vec![];
+
+        for anchor in self.anchors( name )? {
+            deleted_links.extend( anchor.delete_all_my_links_to_target( target, None )? );
+        }
+
+        Ok( deleted_links )
+    }
+
+    /// Remove the links for the name regardless of who created them (eg. any contributor of a
+    /// group maintainer); delete validation decides who is allowed
+    pub fn remove_all<T>(&self, name: &str, target: &T) -> ExternResult<Vec<ActionHash>>
+    where
+        T: Into<AnyLinkableHash> + Clone,
+    {
+        let mut deleted_links = vec![];
+
+        for anchor in self.anchors( name )? {
+            deleted_links.extend( anchor.delete_all_links_to_target( target, None )? );
+        }
+
+        Ok( deleted_links )
+    }
+
+    /// Get the links for every name starting with the given prefix (sorted by name)
+    pub fn search(&self, prefix: &str) -> ExternResult<Vec<Link>> {
+        let prefix = normalize_name( prefix );
+
+        if prefix.is_empty() {
+            return Err(guest_error!(format!(
+                "Name prefix cannot be empty"
+            )));
+        }
+
+        if prefix.chars().count() < NAME_SHARD_LENGTH {
+            return Err(guest_error!(format!(
+                "Name prefix must be at least {} characters", NAME_SHARD_LENGTH,
+            )));
+        }
+
+        let gram = name_prefix_trigram( &prefix )
+            .ok_or(guest_error!(format!(
+                "Name prefix cannot be empty"
+            )))?;
+        let anchor = self.anchor( &name_shard( &prefix ), &gram )?;
+        let tag_prefix = LinkTag::new( prefix.as_bytes().to_vec() );
+
+        let mut seen = BTreeSet::new();
+        let mut links : Vec<Link> = anchor.get_links( Some( tag_prefix ) )?.into_iter()
+            .filter( |link| seen.insert( link.target.clone() ) )
+            .collect();
+
+        links.sort_by( |a, b| a.tag.0.cmp( &b.tag.0 ) );
+
+        Ok( links )
+    }
+
+    /// Get the links for names similar to the query (most similar first)
+    ///
+    /// Candidates are the names linked from any of the query's trigram anchors in the query's
+    /// shard.
+    pub fn fuzzy_search(&self, query: &str) -> ExternResult<Vec<Link>> {
+        let query = normalize_name( query );
+
+        if query.is_empty() {
+            return Err(guest_error!(format!(
+                "Name query cannot be empty"
+            )));
+        }
+
+        let mut candidates : BTreeMap<AnyLinkableHash, Link> = BTreeMap::new();
+
+        for anchor in self.anchors( &query )? {
+            for link in anchor.get_links( None )? {
+                candidates.entry( link.target.clone() ).or_insert( link );
+            }
+        }
+
+        let mut matches : Vec<(f64, Link)> = candidates.into_values()
+            .filter_map( |link| {
+                let name = String::from_utf8( link.tag.0.clone() ).ok()?;
+                let score = name_similarity( &query, &name );
+
+                match score >= FUZZY_MATCH_THRESHOLD {
+                    true => Some( (score, link) ),
+                    false => None,
+                }
+            })
+            .collect();
+
+        matches.sort_by( |(a_score, a), (b_score, b)| {
+            b_score.total_cmp( a_score ).then_with( || a.tag.0.cmp( &b.tag.0 ) )
+        });
+
+        Ok( matches.into_iter().map( |(_, link)| link ).collect() )
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hc_crud,
+    LinkBase,
+    Page,
+    PageQuery,
+    paginate_links,
+    create_link_input,
+};
+
+use std::{
+    str,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+};
+use hdk::prelude::*;
+use hdk_extensions::{
+    hdi_extensions::{
+        guest_error,
+    },
+};
+use hc_crud::{
+    EntityId,
+};
+use semver::{
+    Version,
+};
+
+
+/// A package entity used as the base of its version links
+///
+/// Each hub wraps this with its own link type and version getter (eg. `ZomePackageBase`).
+pub struct PackageBase<LT>(pub EntityId, pub LT)
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+;
+
+impl<LT> PackageBase<LT>
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub fn new(id: &EntityId, version_link_type: LT) -> Self {
+        Self( id.to_owned(), version_link_type )
+    }
+
+    pub fn id(&self) -> EntityId {
+        self.0.to_owned()
+    }
+
+    pub fn version_link_base(&self) -> LinkBase<LT> {
+        LinkBase::new( self.id(), self.1 )
+    }
+
+    /// Link a version to this package unless another version has already claimed the tag
+    ///
+    /// This is a best-effort check against the links visible to this agent; integrity can only
+    /// reject an author claiming the same tag twice (see `devhub_types::check_tag_unclaimed`).
+    /// Concurrent claims by different maintainers are resolved to the oldest link when read.
+    pub fn create_version_link(&self, version_id: &ActionHash, version_name: &str ) -> ExternResult<ActionHash> {
+        let tag = version_name.as_bytes().to_vec();
+        let versions_base = self.version_link_base();
+        let target : AnyLinkableHash = version_id.to_owned().into();
+
+        if let Some(link) = self.links_for_version( version_name )?.into_iter()
+            .find( |link| link.target != target )
+        {
+            return Err(guest_error!(format!(
+                "Version '{}' is already claimed by {}",
+                version_name, link.target,
+            )));
+        }
+
+        Ok(
+            match versions_base.links_exist( version_id, tag )? {
+                Some(link) => link.create_link_hash,
+                None => versions_base.create_link(
+                    version_id, version_name.as_bytes().to_vec()
+                )?,
+            }
+        )
+    }
+
+    pub fn version_links(&self) -> ExternResult<Vec<Link>> {
+        get_links(
+            create_link_input(
+                &self.id(),
+                &self.1,
+                &None::<()>,
+            )?
+        )
+    }
+
+    /// Version links in ascending timestamp order
+    pub fn sorted_version_links(&self) -> ExternResult<Vec<Link>> {
+        let mut version_links = self.version_links()?;
+
+        version_links.sort_by_key( |link| link.timestamp );
+
+        Ok( version_links )
+    }
+
+    /// Links tagged with the given version; ordered by timestamp so that the first link is the
+    /// original claim
+    pub fn links_for_version(&self, version: &str ) -> ExternResult<Vec<Link>> {
+        Ok(
+            self.sorted_version_links()?.into_iter()
+                .filter_map(|link| {
+                    let tag = str::from_utf8( &link.tag.0 )
+                        .map( |value| value.to_string() )
+                        .map_err( |err| {
+                            debug!("Failed to parse version from tag {:?}: {:#?}", link.tag, err );
+                        }).ok()?;
+
+                    match tag == version {
+                        true => Some( link ),
+                        false => None,
+                    }
+                })
+                .collect()
+        )
+    }
+
+    /// Map of version tags to their targets.  When more than one link claims the same tag, the
+    /// oldest claim is used.
+    pub fn version_targets(&self) -> ExternResult<BTreeMap<String, EntityId>> {
+        let mut version_targets = BTreeMap::new();
+
+        self.sorted_version_links()?.iter()
+            .filter_map(|link| {
+                if let Some(target) = link.target.clone().into_action_hash() {
+                    Some(( link, target ))
+                } else {
+                    debug!("Skipping link target because it is not an ActionHash; {:#?}", link.target );
+                    None
+                }
+            })
+            .filter_map(|(link, target)| {
+                Some((
+                    str::from_utf8( &link.tag.0 )
+                        .map( |value| value.to_string() )
+                        .map_err( |err| {
+                            debug!("Failed to parse version from tag {:?}: {:#?}", link.tag, err );
+                        }).ok()?,
+                    target,
+                ))
+            })
+            .for_each(|(vtag, target)| {
+                version_targets.entry( vtag ).or_insert( target );
+            });
+
+        Ok( version_targets )
+    }
+
+    /// Version targets whose tag is a valid semver, ordered from highest to lowest version
+    pub fn semver_targets(&self) -> ExternResult<Vec<(Version, EntityId)>> {
+        let mut version_targets = self.version_targets()?.into_iter()
+            .filter_map(|(vtag, version_id)| {
+                match Version::parse( &vtag ) {
+                    Ok(version) => Some(( version, version_id )),
+                    Err(err) => {
+                        debug!("Skipping version '{}' because it is not a valid semver: {:?}", vtag, err );
+                        None
+                    },
+                }
+            })
+            .collect::<Vec<(Version, EntityId)>>();
+
+        version_targets.sort_by( |(a, _), (b, _)| b.cmp( a ) ); // Descending version order
+
+        Ok( version_targets )
+    }
+
+    /// Map of version tags to the result of `get_version`; versions that fail are dropped
+    pub fn versions<T,F>(&self, get_version: F) -> ExternResult<BTreeMap<String, T>>
+    where
+        F: Fn(EntityId) -> ExternResult<T>,
+    {
+        let version_targets = self.version_targets()?;
+        let mut version_map = BTreeMap::new();
+
+        for (vname, version_id) in version_targets.into_iter() {
+            debug!("Get package version: {}", version_id );
+            let version = match get_version( version_id ) {
+                Ok(value) => value,
+                Err(err) => {
+                    debug!("Dropping version '{}' because of failure to get version info: {:#?}", vname, err );
+                    continue;
+                },
+            };
+            version_map.insert( vname, version );
+        }
+
+        Ok( version_map )
+    }
+
+    /// A page of `(version tag, get_version result)` in link order; versions that fail are dropped
+    ///
+    /// Version tags are only unique per author, so duplicate claims are dropped (keeping the
+    /// oldest, like [`Self::version_targets`]) before paging.  That requires every version link,
+    /// so the cursor does not bound the `get_links` query.
+    pub fn versions_page<T,F>(&self, query: &PageQuery, get_version: F) ->
+        ExternResult<Page<(String, T)>>
+    where
+        F: Fn(EntityId) -> ExternResult<T>,
+    {
+        let mut claimed_tags = BTreeSet::new();
+        let version_links : Vec<Link> = self.sorted_version_links()?.into_iter()
+            .filter( |link| claimed_tags.insert( link.tag.0.clone() ) )
+            .collect();
+
+        Ok(
+            paginate_links( version_links, query )
+                .filter_map(|link| {
+                    let vname = str::from_utf8( &link.tag.0 ).ok()?.to_string();
+                    let version_id = link.target.into_action_hash()?;
+
+                    match get_version( version_id ) {
+                        Ok(version) => Some(( vname, version )),
+                        Err(err) => {
+                            debug!("Dropping version '{}' because of failure to get version info: {:#?}", vname, err );
+                            None
+                        },
+                    }
+                })
+        )
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    hdk,
+};
+
+use hdk::prelude::*;
+
+
+pub const DEFAULT_PAGE_LIMIT : usize = 50;
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+
+/// Position of a link in the (timestamp, create link hash) ordering
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkCursor {
+    pub timestamp: Timestamp,
+    pub create_link_hash: ActionHash,
+}
+
+impl LinkCursor {
+    pub fn key(&self) -> (Timestamp, &ActionHash) {
+        ( self.timestamp, &self.create_link_hash )
+    }
+}
+
+impl From<&Link> for LinkCursor {
+    fn from(link: &Link) -> Self {
+        Self {
+            timestamp: link.timestamp,
+            create_link_hash: link.create_link_hash.clone(),
+        }
+    }
+}
+
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PageQuery {
+    pub limit: Option<usize>,
+    pub cursor: Option<LinkCursor>,
+    #[serde(default)]
+    pub sort: SortOrder,
+}
+
+impl PageQuery {
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or( DEFAULT_PAGE_LIMIT )
+    }
+}
+
+
+/// Input for list externs that also take a base value (eg. an agent or package ID)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PaginatedInput<T> {
+    pub input: T,
+    #[serde(default)]
+    pub page: PageQuery,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next page; `None` when this is the last page
+    pub next_cursor: Option<LinkCursor>,
+}
+
+impl<T> Page<T> {
+    /// Transform the items while keeping the cursor of the underlying links
+    ///
+    /// Items that are dropped do not shift the cursor, so a page may contain fewer items than the
+    /// requested limit even when there are more pages.
+    pub fn filter_map<U,F>(self, f: F) -> Page<U>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        Page {
+            items: self.items.into_iter().filter_map( f ).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+
+/// Sort links by (timestamp, create link hash) and select the page described by `query`
+pub fn paginate_links(mut links: Vec<Link>, query: &PageQuery) -> Page<Link> {
+    links.sort_by( |a, b| {
+        ( a.timestamp, &a.create_link_hash ).cmp( &( b.timestamp, &b.create_link_hash ) )
+    });
+
+    if query.sort == SortOrder::Descending {
+        links.reverse();
+    }
+
+    let limit = query.limit();
+    let mut items : Vec<Link> = links.into_iter()
+        .filter( |link| match &query.cursor {
+            // The cursor link itself may have been deleted, so compare keys instead of searching
+            // for it
+            Some(cursor) => {
+                let key = ( link.timestamp, &link.create_link_hash );
+
+                match query.sort {
+                    SortOrder::Ascending => key > cursor.key(),
+                    SortOrder::Descending => key < cursor.key(),
+                }
+            },
+            None => true,
+        })
+        .take( limit + 1 )
+        .collect();
+
+    let next_cursor = match items.len() > limit {
+        true => {
+            items.truncate( limit );
+            items.last().map( LinkCursor::from )
+        },
+        false => None,
+    };
+
+    Page {
+        items,
+        next_cursor,
+    }
+}
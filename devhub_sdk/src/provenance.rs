@@ -0,0 +1,158 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hc_crud,
+    LinkBase,
+};
+
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    hdi_extensions::{
+        guest_error,
+    },
+};
+use hc_crud::{
+    Entity, EntityId,
+    EntryModel,
+    create_entity, get_entity, delete_entity,
+};
+use devhub_types::{
+    BuildProvenance,
+    RebuildAttestationEntry,
+};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SubmitRebuildAttestationInput {
+    pub for_version: ActionHash,
+    pub hash: String,
+
+    // optional
+    pub build_provenance: Option<BuildProvenance>,
+}
+
+impl TryFrom<SubmitRebuildAttestationInput> for RebuildAttestationEntry {
+    type Error = WasmError;
+
+    fn try_from(input: SubmitRebuildAttestationInput) -> ExternResult<Self> {
+        Ok(
+            Self {
+                attester: agent_id()?,
+                for_version: input.for_version,
+                hash: input.hash,
+                build_provenance: input.build_provenance,
+            }
+        )
+    }
+}
+
+
+/// A package version with the number of rebuild attestations that match its artifact hash
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AttestedVersion<T> {
+    pub version: Entity<T>,
+    pub matching_rebuild_attestations: u64,
+}
+
+
+/// Rebuild attestation handlers shared by the hubs
+///
+/// Each hub supplies its entry types and the link type from a version to its attestations.
+/// Resolving the artifact hash that attestations are compared against stays in the hub.
+pub struct RebuildAttestationLinks<ET, LT>
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub version_to_attestation: LT,
+    entry_types: PhantomData<ET>,
+}
+
+impl<ET, LT> RebuildAttestationLinks<ET, LT>
+where
+    RebuildAttestationEntry: EntryModel<ET>,
+    ScopedEntryDefIndex: for<'a> TryFrom<&'a ET, Error = WasmError>,
+    EntryVisibility: for<'a> From<&'a ET>,
+    Entry: TryFrom<ET, Error = WasmError>,
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub fn new(version_to_attestation: LT) -> Self {
+        Self {
+            version_to_attestation,
+            entry_types: PhantomData,
+        }
+    }
+
+    pub fn version_base(&self, version_id: &EntityId) -> LinkBase<LT> {
+        LinkBase::new( version_id.to_owned(), self.version_to_attestation )
+    }
+
+    pub fn submit_rebuild_attestation(&self, input: SubmitRebuildAttestationInput) ->
+        ExternResult<Entity<RebuildAttestationEntry>>
+    {
+        let version_base = self.version_base( &input.for_version );
+        let me = agent_id()?;
+
+        if let Some(link) = version_base.get_links( None )?.into_iter()
+            .find( |link| link.author == me )
+        {
+            return Err(guest_error!(format!(
+                "Agent {} has already attested version ({}); see attestation ({})",
+                me, input.for_version, link.target,
+            )));
+        }
+
+        let entry : RebuildAttestationEntry = input.try_into()?;
+        let entity = create_entity::<RebuildAttestationEntry,ET,WasmError>( &entry )?;
+
+        version_base.create_link_if_not_exists( &entity.id, () )?;
+
+        Ok( entity )
+    }
+
+    pub fn get_rebuild_attestation(&self, addr: &EntityId) ->
+        ExternResult<Entity<RebuildAttestationEntry>>
+    {
+        get_entity::<RebuildAttestationEntry,ET>( addr )
+    }
+
+    pub fn get_rebuild_attestations_for_version(&self, version_id: &EntityId) ->
+        ExternResult<Vec<Entity<RebuildAttestationEntry>>>
+    {
+        Ok(
+            self.version_base( version_id ).get_links( None )?.into_iter()
+                .filter_map(|link| {
+                    let addr = link.target.into_action_hash()?;
+                    self.get_rebuild_attestation( &addr ).ok()
+                })
+                .collect()
+        )
+    }
+
+    /// Count the distinct attesters whose hash matches the version's artifact hash
+    pub fn count_matching_rebuild_attestations(&self, version_id: &EntityId, hash: &str) ->
+        ExternResult<u64>
+    {
+        let attesters : BTreeSet<AgentPubKey> = self.get_rebuild_attestations_for_version( version_id )?
+            .into_iter()
+            .filter( |attestation| attestation.content.hash == hash )
+            .map( |attestation| attestation.content.attester )
+            .collect();
+
+        Ok( attesters.len() as u64 )
+    }
+
+    pub fn delete_rebuild_attestation(&self, id: &EntityId) -> ExternResult<ActionHash> {
+        let attestation = self.get_rebuild_attestation( id )?.content;
+        let delete_addr = delete_entity::<RebuildAttestationEntry,ET>( id )?;
+
+        self.version_base( &attestation.for_version )
+            .delete_all_my_links_to_target( id, None )?;
+
+        Ok( delete_addr )
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    hc_crud,
+    LinkBase,
+};
+
+use std::marker::PhantomData;
+use hdk::prelude::*;
+use hdk_extensions::{
+    agent_id,
+    hdi_extensions::{
+        guest_error,
+    },
+};
+use hc_crud::{
+    Entity, EntityId,
+    EntryModel,
+    create_entity, get_entity, update_entity, delete_entity,
+    UpdateEntityInput,
+};
+use devhub_types::{
+    ReviewEntry,
+    ReviewVerdict,
+    ReviewSummary,
+};
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreateReviewInput {
+    pub for_version: ActionHash,
+    pub verdict: ReviewVerdict,
+    pub message: String,
+
+    // optional
+    pub security_score: Option<u8>,
+}
+
+impl TryFrom<CreateReviewInput> for ReviewEntry {
+    type Error = WasmError;
+
+    fn try_from(input: CreateReviewInput) -> ExternResult<Self> {
+        Ok(
+            Self {
+                reviewer: agent_id()?,
+                for_version: input.for_version,
+                verdict: input.verdict,
+                message: input.message,
+                security_score: input.security_score,
+            }
+        )
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdateReviewInput {
+    pub verdict: Option<ReviewVerdict>,
+    pub message: Option<String>,
+    pub security_score: Option<u8>,
+}
+
+
+/// Review handlers shared by the hubs
+///
+/// Each hub supplies its entry types and the link types that index reviews by version and by
+/// agent (eg. `ZomePackageVersionToReview` and `AgentToReview`).
+pub struct ReviewLinks<ET, LT>
+where
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub version_to_review: LT,
+    pub agent_to_review: LT,
+    entry_types: PhantomData<ET>,
+}
+
+impl<ET, LT> ReviewLinks<ET, LT>
+where
+    ReviewEntry: EntryModel<ET>,
+    ScopedEntryDefIndex: for<'a> TryFrom<&'a ET, Error = WasmError>,
+    EntryVisibility: for<'a> From<&'a ET>,
+    Entry: TryFrom<ET, Error = WasmError>,
+    LT: LinkTypeFilterExt + Copy,
+    ScopedLinkType: TryFrom<LT, Error = WasmError>,
+{
+    pub fn new(version_to_review: LT, agent_to_review: LT) -> Self {
+        Self {
+            version_to_review,
+            agent_to_review,
+            entry_types: PhantomData,
+        }
+    }
+
+    pub fn version_base(&self, version_id: &EntityId) -> LinkBase<LT> {
+        LinkBase::new( version_id.to_owned(), self.version_to_review )
+    }
+
+    pub fn agent_base(&self, agent: &AgentPubKey) -> LinkBase<LT> {
+        LinkBase::new( agent.to_owned(), self.agent_to_review )
+    }
+
+    pub fn create_review(&self, input: CreateReviewInput) -> ExternResult<Entity<ReviewEntry>> {
+        let version_base = self.version_base( &input.for_version );
+        let me = agent_id()?;
+
+        // A reviewer should update their existing review instead of adding another
+        if let Some(link) = version_base.get_links( None )?.into_iter()
+            .find( |link| link.author == me )
+        {
+            return Err(guest_error!(format!(
+                "Agent {} has already reviewed version ({}); see review ({})",
+                me, input.for_version, link.target,
+            )));
+        }
+
+        let entry : ReviewEntry = input.try_into()?;
+        let entity = create_entity::<ReviewEntry,ET,WasmError>( &entry )?;
+
+        version_base.create_link_if_not_exists( &entity.id, () )?;
+        self.agent_base( &me ).create_link_if_not_exists( &entity.id, () )?;
+
+        Ok( entity )
+    }
+
+    pub fn get_review(&self, addr: &EntityId) -> ExternResult<Entity<ReviewEntry>> {
+        get_entity::<ReviewEntry,ET>( addr )
+    }
+
+    /// Reviews in the order their links were created
+    fn get_linked_reviews(&self, base: LinkBase<LT>) -> ExternResult<Vec<Entity<ReviewEntry>>> {
+        let mut links = base.get_links( None )?;
+
+        links.sort_by_key( |link| link.timestamp );
+
+        Ok(
+            links.into_iter()
+                .filter_map(|link| {
+                    let addr = link.target.into_action_hash()?;
+                    self.get_review( &addr ).ok()
+                })
+                .collect()
+        )
+    }
+
+    pub fn get_reviews_for_version(&self, version_id: &EntityId) ->
+        ExternResult<Vec<Entity<ReviewEntry>>>
+    {
+        self.get_linked_reviews( self.version_base( version_id ) )
+    }
+
+    /// Reviews by the given agent, or by this agent when `None`
+    pub fn get_reviews_for_agent(&self, maybe_agent_id: Option<AgentPubKey>) ->
+        ExternResult<Vec<Entity<ReviewEntry>>>
+    {
+        let agent_id = match maybe_agent_id {
+            Some(agent_id) => agent_id,
+            None => agent_id()?,
+        };
+
+        self.get_linked_reviews( self.agent_base( &agent_id ) )
+    }
+
+    pub fn get_review_summary(&self, version_id: &EntityId) -> ExternResult<ReviewSummary> {
+        let reviews = self.get_reviews_for_version( version_id )?;
+
+        Ok( ReviewSummary::from_reviews( reviews.iter().map( |entity| &entity.content ) ) )
+    }
+
+    pub fn update_review(&self, input: UpdateEntityInput<UpdateReviewInput>) ->
+        ExternResult<Entity<ReviewEntry>>
+    {
+        let props = input.properties;
+
+        let entity = update_entity::<ReviewEntry,ET,_,WasmError>(
+            &input.base,
+            |mut current : ReviewEntry, _| {
+                current.verdict = props.verdict
+                    .unwrap_or( current.verdict );
+                current.message = props.message
+                    .unwrap_or( current.message );
+                current.security_score = props.security_score
+                    .or( current.security_score );
+
+                Ok( current )
+            })?;
+
+        Ok( entity )
+    }
+
+    pub fn delete_review(&self, id: &EntityId) -> ExternResult<ActionHash> {
+        let review = self.get_review( id )?.content;
+        let delete_addr = delete_entity::<ReviewEntry,ET>( id )?;
+
+        self.version_base( &review.for_version )
+            .delete_all_my_links_to_target( id, None )?;
+        self.agent_base( &review.reviewer )
+            .delete_all_my_links_to_target( id, None )?;
+
+        Ok( delete_addr )
+    }
+}
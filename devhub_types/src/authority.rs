@@ -0,0 +1,222 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use hdi::prelude::*;
+use hdi_extensions::{
+    trace_origin_root,
+    summon_app_entry,
+    // Macros
+    valid, invalid,
+};
+use coop_content_types::{
+    GroupEntry,
+};
+
+
+
+/// The agent or coop-content group that maintains a package (or package version)
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "type", content = "content")]
+#[serde(rename_all = "snake_case")]
+pub enum Authority {
+    Group(ActionHash, ActionHash),
+    Agent(AgentPubKey),
+}
+
+impl From<AgentPubKey> for Authority {
+    fn from(agent_pub_key: AgentPubKey) -> Self {
+        Authority::Agent(agent_pub_key)
+    }
+}
+
+impl From<(ActionHash, ActionHash)> for Authority {
+    fn from((group_id, group_addr): (ActionHash, ActionHash)) -> Self {
+        Authority::Group(group_id, group_addr)
+    }
+}
+
+
+/// An entry that declares its maintainer (eg. a package entry)
+pub trait Maintained {
+    fn maintainer(&self) -> &Authority;
+}
+
+
+/// Check that an agent is the maintainer, or a contributor of the maintainer group
+pub fn check_authority(
+    authority: &Authority,
+    agent_pubkey: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    match authority {
+        Authority::Agent(authority_agent) => {
+            if agent_pubkey != authority_agent {
+                invalid!(format!(
+                    "{} is not the maintainer ({})",
+                    agent_pubkey, authority_agent,
+                ))
+            }
+        },
+        Authority::Group(group_id, group_rev) => {
+            let group : GroupEntry = summon_app_entry( &group_rev.to_owned().into() )?;
+
+            if !group.is_contributor( agent_pubkey ) {
+                invalid!(format!(
+                    "{} is not authorized in group {}",
+                    agent_pubkey, group_id,
+                ))
+            }
+
+            // Check that group_rev belongs to group_id
+            if *group_id != trace_origin_root( group_rev )?.0 {
+                invalid!(format!(
+                    "Group rev {} is not a descendant of group ID {}",
+                    group_rev, group_id,
+                ))
+            }
+        },
+    }
+
+    valid!()
+}
+
+
+/// Check an agent's authority over a package using the latest revision they know of
+///
+/// The agent's chain (up to `chain_top`) is searched for their latest update of the package; if
+/// they have never updated it, the maintainer of the original package entry is used.
+pub fn check_package_authority<T,E>(
+    package_entry_type: &EntryType,
+    package_id: &ActionHash,
+    agent_pubkey: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<ValidateCallbackResult>
+where
+    T: Maintained + TryFrom<Record, Error = E> + TryFrom<Entry, Error = E>,
+    E: std::fmt::Debug,
+    WasmError: From<E>,
+{
+    let activity = must_get_agent_activity(
+        agent_pubkey.to_owned(),
+        ChainFilter::new( chain_top.to_owned() ),
+    )?;
+
+    let mut latest : Option<(u32, EntryHash)> = None;
+
+    for activity in activity.iter() {
+        if let Action::Update(update) = &activity.action.hashed.content {
+            if update.entry_type != *package_entry_type
+                || latest.as_ref().is_some_and( |(seq, _)| *seq > update.action_seq )
+            {
+                continue;
+            }
+
+            if trace_origin_root( &activity.action.hashed.hash )?.0 == *package_id {
+                latest = Some(( update.action_seq, update.entry_hash.to_owned() ));
+            }
+        }
+    }
+
+    let package : T = match latest {
+        Some((_, entry_hash)) => summon_app_entry( &entry_hash.into() )?,
+        None => summon_app_entry( &package_id.to_owned().into() )?,
+    };
+
+    check_authority( package.maintainer(), agent_pubkey )
+}
+
+
+/// Check that a version's maintainer is the same agent or group as its package's maintainer
+///
+/// A group maintainer may point to a newer revision of the group than the package does.
+pub fn check_maintainer_matches_package(
+    package_maintainer: &Authority,
+    maintainer: &Authority,
+) -> ExternResult<ValidateCallbackResult> {
+    match ( package_maintainer, maintainer ) {
+
+        // Should have matching agent authority
+        (
+            Authority::Agent(expected_agent),
+            Authority::Agent(agent)
+        ) => {
+            if expected_agent != agent {
+                invalid!(format!(
+                    "Maintainer agent must match parent package: {} != {}",
+                    expected_agent, agent,
+                ))
+            }
+        },
+
+        // Should have matching group ID
+        (
+            Authority::Group(expected_group_id, _),
+            Authority::Group(group_id, group_rev)
+        ) => {
+            // Ensure same group ID
+            if expected_group_id != group_id {
+                invalid!(format!(
+                    "Maintainer group must match parent package: {} != {}",
+                    expected_group_id, group_id,
+                ))
+            }
+
+            // Ensure group rev is related to group ID
+            if trace_origin_root( group_rev )?.0 != *group_id {
+                invalid!(format!(
+                    "Maintainer group revision ({}) must be an evolution of group ID ({})",
+                    group_rev, group_id,
+                ))
+            }
+        },
+
+        (expected_maintainer, maintainer) => {
+            invalid!(format!(
+                "Maintainer type must match parent package: {:?} != {:?}",
+                maintainer, expected_maintainer,
+            ))
+        },
+    }
+
+    valid!()
+}
+
+
+/// Check that an update keeps the same maintainer agent or group
+pub fn check_maintainer_unchanged(
+    previous_maintainer: &Authority,
+    maintainer: &Authority,
+) -> ExternResult<ValidateCallbackResult> {
+    match ( previous_maintainer, maintainer ) {
+        (
+            Authority::Group(prev_group_id, _),
+            Authority::Group(group_id, _),
+        ) => {
+            if prev_group_id != group_id {
+                invalid!(format!(
+                    "The maintainer group cannot be changed: {} => {}",
+                    prev_group_id, group_id,
+                ))
+            }
+        },
+        (
+            Authority::Agent(prev_agent_pubkey),
+            Authority::Agent(agent_pubkey),
+        ) => {
+            if prev_agent_pubkey != agent_pubkey {
+                invalid!(format!(
+                    "The maintainer agent cannot be changed: {} => {}",
+                    prev_agent_pubkey, agent_pubkey,
+                ))
+            }
+        },
+        (expected_maintainer, maintainer) => {
+            invalid!(format!(
+                "Maintainer type cannot be changed: {:?} => {:?}",
+                expected_maintainer, maintainer,
+            ))
+        },
+    }
+
+    valid!()
+}
@@ -0,0 +1,17 @@
+mod authority;
+mod version_tag;
+mod name_index;
+mod memory;
+mod storage_quota;
+mod review;
+mod provenance;
+
+pub use hdi_extensions::hdi;
+pub use hdi_extensions;
+pub use authority::*;
+pub use version_tag::*;
+pub use name_index::*;
+pub use memory::*;
+pub use storage_quota::*;
+pub use review::*;
+pub use provenance::*;
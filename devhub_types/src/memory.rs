@@ -0,0 +1,35 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use std::io::Read;
+use hdi::prelude::*;
+use hdi_extensions::{
+    guest_error,
+};
+use flate2::read::GzDecoder;
+
+
+/// Expand memory bytes according to the `MemoryEntry.compression` value
+pub fn decompress_memory_bytes(compression: &Option<String>, bytes: Vec<u8>) ->
+    ExternResult<Vec<u8>>
+{
+    match compression.as_deref() {
+        None => Ok( bytes ),
+        Some("gzip") => gunzip( &bytes ),
+        Some(other) => Err(guest_error!(format!(
+            "Unsupported memory compression '{}'", other,
+        ))),
+    }
+}
+
+
+pub fn gunzip(bytes: &[u8]) -> ExternResult<Vec<u8>> {
+    let mut buf = Vec::new();
+
+    GzDecoder::new( bytes ).read_to_end( &mut buf )
+        .map_err( |err| guest_error!(format!("Failed to decompress bytes: {:?}", err )) )?;
+
+    Ok( buf )
+}
+
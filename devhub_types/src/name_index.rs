@@ -0,0 +1,164 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use std::str;
+use std::collections::BTreeSet;
+use hdi::prelude::*;
+use hdi_extensions::{
+    // Macros
+    valid, invalid,
+};
+
+
+pub const NAME_INDEX_ROOT : &str = "names";
+
+
+/// Lowercase and trim a name so that index anchors and link tags are case-insensitive
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Padding added before and after a name (like pg_trgm) so that its first characters and its end
+/// have their own trigrams
+pub const NAME_START_PADDING : &str = "^^";
+pub const NAME_END_PADDING : &str = "$";
+
+/// All trigrams of the padded, normalized name
+///
+/// Eg. "mere" has the trigrams "^^m", "^me", "mer", "ere" and "re$".
+pub fn name_trigrams(name: &str) -> BTreeSet<String> {
+    let padded = format!("{}{}{}", NAME_START_PADDING, normalize_name( name ), NAME_END_PADDING );
+    let chars : Vec<char> = padded.chars().collect();
+
+    chars.windows(3)
+        .map( |gram| gram.iter().collect() )
+        .collect()
+}
+
+/// Number of leading characters of the normalized name used to shard its anchors
+pub const NAME_SHARD_LENGTH : usize = 2;
+
+/// The shard for a name's anchors; the first [`NAME_SHARD_LENGTH`] characters of the normalized
+/// name
+pub fn name_shard(name: &str) -> String {
+    normalize_name( name ).chars().take( NAME_SHARD_LENGTH ).collect()
+}
+
+/// The trigram anchor used for a name prefix search; `None` when the normalized prefix is empty
+///
+/// Every trigram of the start-padded prefix is also a trigram of each name that starts with that
+/// prefix; the last one is used because it is the most selective.
+pub fn name_prefix_trigram(prefix: &str) -> Option<String> {
+    let prefix = normalize_name( prefix );
+
+    if prefix.is_empty() {
+        return None;
+    }
+
+    let padded = format!("{}{}", NAME_START_PADDING, prefix );
+    let chars : Vec<char> = padded.chars().collect();
+
+    Some( chars[ chars.len() - 3 .. ].iter().collect() )
+}
+
+/// Path for the trigram anchor `names.<shard>.<gram>` (see [`name_shard`])
+///
+/// Components are built directly so that a '.' in a name does not add path segments.
+pub fn name_index_path(shard: &str, gram: &str) -> Path {
+    Path::from( vec![
+        Component::new( NAME_INDEX_ROOT.as_bytes().to_vec() ),
+        Component::new( shard.as_bytes().to_vec() ),
+        Component::new( gram.as_bytes().to_vec() ),
+    ])
+}
+
+/// Every anchor that a name is linked from (one per trigram, in the name's shard)
+pub fn name_index_anchor_hashes(name: &str) -> ExternResult<Vec<EntryHash>> {
+    let shard = name_shard( name );
+
+    name_trigrams( name ).iter()
+        .map( |gram| name_index_path( &shard, gram ).path_entry_hash() )
+        .collect()
+}
+
+
+/// Ensure a name index link tag is a normalized name and that the base is one of the anchors for
+/// that name, so links cannot be placed on arbitrary anchors
+pub fn check_name_index_link(
+    base_address: &AnyLinkableHash,
+    tag: &LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let name = match str::from_utf8( &tag.0 ) {
+        Ok(name) => name,
+        Err(err) => invalid!(format!(
+            "Name index tag is not valid UTF-8: {:?}", err,
+        )),
+    };
+
+    if name.is_empty() || normalize_name( name ) != name {
+        invalid!(format!(
+            "Name index tag '{}' is not a normalized name", name,
+        ))
+    }
+
+    let is_anchor = name_index_anchor_hashes( name )?.into_iter()
+        .any( |hash| AnyLinkableHash::from( hash ) == *base_address );
+
+    if !is_anchor {
+        invalid!(format!(
+            "Name index link base ({}) is not an anchor for name '{}'",
+            base_address, name,
+        ))
+    }
+
+    valid!()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grams(grams: &[&str]) -> BTreeSet<String> {
+        grams.iter().map( |gram| gram.to_string() ).collect()
+    }
+
+    #[test]
+    fn trigrams_are_padded_and_normalized() {
+        assert_eq!(
+            name_trigrams(" Mere "),
+            grams(&[ "^^m", "^me", "mer", "ere", "re$" ]),
+        );
+    }
+
+    #[test]
+    fn trigrams_of_short_names() {
+        assert_eq!( name_trigrams("a"), grams(&[ "^^a", "^a$" ]) );
+        assert_eq!( name_trigrams(""), grams(&[ "^^$" ]) );
+    }
+
+    #[test]
+    fn shard_is_first_characters() {
+        assert_eq!( name_shard(" MERE_memory"), "me" );
+        assert_eq!( name_shard("m"), "m" );
+        assert_eq!( name_shard("über"), "üb" );
+    }
+
+    #[test]
+    fn prefix_trigram_is_a_trigram_of_matching_names() {
+        for prefix in [ "m", "me", "mere_m" ] {
+            let gram = name_prefix_trigram( prefix ).unwrap();
+
+            assert!( name_trigrams("mere_memory").contains( &gram ), "{} -> {}", prefix, gram );
+        }
+
+        assert_eq!( name_prefix_trigram("mere_m"), Some( "e_m".to_string() ) );
+    }
+
+    #[test]
+    fn prefix_trigram_of_empty_prefix() {
+        assert_eq!( name_prefix_trigram(""), None );
+        assert_eq!( name_prefix_trigram("   "), None );
+    }
+}
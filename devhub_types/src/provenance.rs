@@ -0,0 +1,74 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use std::collections::BTreeMap;
+use hdi::prelude::*;
+use hdi_extensions::{
+    // Macros
+    valid, invalid,
+};
+
+
+/// How a package version's artifact was built so that others can reproduce it
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct BuildProvenance {
+    pub repository_url: String,
+    pub commit_hash: String,
+    /// Tool name to version (eg. `rustc` => `1.75.0`)
+    #[serde(default)]
+    pub toolchain_versions: BTreeMap<String, String>,
+    pub build_command: String,
+    /// Hash of the `flake.lock` used for the build
+    pub flake_lock_hash: Option<String>,
+}
+
+
+
+//
+// Rebuild Attestation Entry
+//
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct RebuildAttestationEntry {
+    /// The agent that rebuilt the artifact
+    ///
+    /// Create
+    ///   - Must be the create author
+    ///   - Cannot be a maintainer of the version
+    pub attester: AgentPubKey,
+
+    /// The ID of the package version that was rebuilt
+    pub for_version: ActionHash,
+
+    /// The hash of the rebuilt artifact (eg. `ZomeEntry.hash`)
+    ///
+    /// The attestation matches when this is the same as the published artifact's hash.
+    pub hash: String,
+
+    /// The build details, when they differ from the version's provenance
+    pub build_provenance: Option<BuildProvenance>,
+}
+
+
+/// Check the parts of an attestation that do not depend on the version's type
+///
+/// The hub's validation must also check that the author is not a maintainer of the version (see
+/// [`crate::check_not_version_maintainer`]).
+pub fn check_rebuild_attestation(
+    attestation: &RebuildAttestationEntry,
+    author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if attestation.attester != *author {
+        invalid!(format!(
+            "Attester must be the author: {} != {}",
+            attestation.attester, author,
+        ))
+    }
+
+    if attestation.hash.is_empty() {
+        invalid!(format!("Rebuild attestation hash cannot be empty"))
+    }
+
+    valid!()
+}
@@ -0,0 +1,211 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    Authority,
+    check_authority,
+};
+use std::collections::BTreeMap;
+use hdi::prelude::*;
+use hdi_extensions::{
+    // Macros
+    valid, invalid,
+};
+
+
+/// The highest allowed [`ReviewEntry`] `security_score`
+pub const MAX_SECURITY_SCORE : u8 = 100;
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewVerdict {
+    Approve,
+    Reject,
+    Concern,
+}
+
+
+
+//
+// Review Entry
+//
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct ReviewEntry {
+    /// The agent that reviewed the version
+    ///
+    /// Create
+    ///   - Must be the create author
+    ///   - Cannot be a maintainer of the reviewed version
+    /// Update
+    ///   - Cannot be updated
+    pub reviewer: AgentPubKey,
+
+    /// The ID of the reviewed package version (eg. a `ZomePackageVersionEntry`)
+    ///
+    /// Update
+    ///   - Cannot be updated
+    pub for_version: ActionHash,
+
+    pub verdict: ReviewVerdict,
+    pub message: String,
+
+    /// Optional score from 0 to [`MAX_SECURITY_SCORE`]
+    pub security_score: Option<u8>,
+}
+
+
+/// Totals for the reviews of a version
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ReviewSummary {
+    pub approvals: u64,
+    pub rejections: u64,
+    pub concerns: u64,
+    /// Average of the reviews that include a `security_score`
+    pub average_security_score: Option<f64>,
+}
+
+impl ReviewSummary {
+    /// Total the given reviews, counting at most 1 review per reviewer
+    ///
+    /// Reviews are expected in creation order so that a reviewer's latest review is the one that
+    /// counts.
+    pub fn from_reviews<'a>(reviews: impl IntoIterator<Item = &'a ReviewEntry>) -> Self {
+        let mut summary = Self::default();
+        let mut score_total : u64 = 0;
+        let mut score_count : u64 = 0;
+
+        let latest_reviews : BTreeMap<&AgentPubKey, &ReviewEntry> = reviews.into_iter()
+            .map( |review| (&review.reviewer, review) )
+            .collect();
+
+        for review in latest_reviews.into_values() {
+            match review.verdict {
+                ReviewVerdict::Approve => summary.approvals += 1,
+                ReviewVerdict::Reject => summary.rejections += 1,
+                ReviewVerdict::Concern => summary.concerns += 1,
+            }
+
+            if let Some(score) = review.security_score {
+                score_total += score as u64;
+                score_count += 1;
+            }
+        }
+
+        if score_count > 0 {
+            summary.average_security_score = Some( score_total as f64 / score_count as f64 );
+        }
+
+        summary
+    }
+}
+
+
+/// Check the parts of a review that do not depend on the reviewed version's type
+///
+/// The hub's validation must also check that the author is not a maintainer of the version (see
+/// [`check_not_version_maintainer`]).
+pub fn check_review(
+    review: &ReviewEntry,
+    author: &AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+    if review.reviewer != *author {
+        invalid!(format!(
+            "Reviewer must be the author: {} != {}",
+            review.reviewer, author,
+        ))
+    }
+
+    if let Some(score) = review.security_score {
+        if score > MAX_SECURITY_SCORE {
+            invalid!(format!(
+                "Security score must be between 0 and {}; not {}",
+                MAX_SECURITY_SCORE, score,
+            ))
+        }
+    }
+
+    valid!()
+}
+
+
+/// Ensure that the author is not a maintainer of the version they review or attest
+///
+/// `maintainer` is the reviewed version's maintainer and `activity` names the action for the
+/// error message (eg. "review").
+pub fn check_not_version_maintainer(
+    version_id: &ActionHash,
+    maintainer: &Authority,
+    author: &AgentPubKey,
+    activity: &str,
+) -> ExternResult<ValidateCallbackResult> {
+    if let ValidateCallbackResult::Valid = check_authority( maintainer, author )? {
+        invalid!(format!(
+            "{} is a maintainer of version ({}) and cannot {} it",
+            author, version_id, activity,
+        ))
+    }
+
+    valid!()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review(reviewer: u8, verdict: ReviewVerdict, security_score: Option<u8>) -> ReviewEntry {
+        ReviewEntry {
+            reviewer: AgentPubKey::from_raw_36( vec![ reviewer; 36 ] ),
+            for_version: ActionHash::from_raw_36( vec![ 0; 36 ] ),
+            verdict,
+            message: String::new(),
+            security_score,
+        }
+    }
+
+    #[test]
+    fn summary_of_no_reviews() {
+        assert_eq!( ReviewSummary::from_reviews( &[] ), ReviewSummary::default() );
+    }
+
+    #[test]
+    fn summary_totals_verdicts_and_scores() {
+        let reviews = vec![
+            review( 1, ReviewVerdict::Approve, Some( 80 ) ),
+            review( 2, ReviewVerdict::Reject, None ),
+            review( 3, ReviewVerdict::Concern, Some( 40 ) ),
+            review( 4, ReviewVerdict::Approve, None ),
+        ];
+
+        assert_eq!(
+            ReviewSummary::from_reviews( &reviews ),
+            ReviewSummary {
+                approvals: 2,
+                rejections: 1,
+                concerns: 1,
+                average_security_score: Some( 60.0 ),
+            },
+        );
+    }
+
+    #[test]
+    fn summary_counts_latest_review_per_reviewer() {
+        let reviews = vec![
+            review( 1, ReviewVerdict::Approve, Some( 100 ) ),
+            review( 1, ReviewVerdict::Approve, Some( 100 ) ),
+            review( 1, ReviewVerdict::Reject, Some( 10 ) ),
+            review( 2, ReviewVerdict::Approve, None ),
+        ];
+
+        assert_eq!(
+            ReviewSummary::from_reviews( &reviews ),
+            ReviewSummary {
+                approvals: 1,
+                rejections: 1,
+                concerns: 0,
+                average_security_score: Some( 10.0 ),
+            },
+        );
+    }
+}
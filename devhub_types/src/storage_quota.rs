@@ -0,0 +1,141 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use hdi::prelude::*;
+use hdi_extensions::{
+    guest_error,
+    // Macros
+    valid, invalid,
+};
+
+
+/// Upload limits that can be set in the DNA properties (eg. `storage_quota.max_file_size`)
+///
+/// Limits that are not set are not enforced.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageQuota {
+    /// Maximum `file_size` of a single entry
+    pub max_file_size: Option<u64>,
+    /// Maximum sum of `file_size` for all the entries of the same type created by an agent
+    pub max_agent_storage: Option<u64>,
+}
+
+
+/// The DNA properties used by the DevHub DNAs
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DevHubProperties {
+    #[serde(default)]
+    pub storage_quota: StorageQuota,
+}
+
+impl DevHubProperties {
+    /// Read the properties from `dna_info`; absent properties (`~`) result in the defaults but
+    /// malformed properties are an error
+    pub fn get() -> ExternResult<Self> {
+        let properties = dna_info()?.modifiers.properties;
+        let maybe_properties : Option<Self> = decode( properties.bytes() )
+            .map_err( |err| guest_error!(format!(
+                "Failed to deserialize DNA properties: {:?}", err,
+            )) )?;
+
+        Ok( maybe_properties.unwrap_or_default() )
+    }
+}
+
+
+/// The most actions of an author's chain that [`sum_agent_entry_sizes`] will read
+pub const MAX_QUOTA_CHAIN_SCAN : u32 = 10_000;
+
+
+/// Sum the sizes of every entry of `entry_type` that an agent created up to `chain_top`
+///
+/// Deleted entries are still counted because their memory blocks remain in the DHT.
+///
+/// Integrity cannot keep a running total, so this walks the author's chain and fetches each
+/// matching entry.  The walk is bounded to [`MAX_QUOTA_CHAIN_SCAN`] actions; callers must reject
+/// longer chains (see [`check_storage_quota`]) because older entries would not be counted.
+pub fn sum_agent_entry_sizes<F>(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    entry_type: &EntryType,
+    size_of: F,
+) -> ExternResult<u64>
+where
+    F: Fn(Entry) -> ExternResult<u64>,
+{
+    let activity = must_get_agent_activity(
+        agent.to_owned(),
+        ChainFilter::new( chain_top.to_owned() ).take( MAX_QUOTA_CHAIN_SCAN ),
+    )?;
+
+    let mut total : u64 = 0;
+
+    for activity in activity.iter() {
+        if let Action::Create(create) = &activity.action.hashed.content {
+            if create.entry_type != *entry_type {
+                continue;
+            }
+
+            let entry = must_get_entry( create.entry_hash.to_owned() )?.content;
+
+            total = total.saturating_add( size_of( entry )? );
+        }
+    }
+
+    Ok( total )
+}
+
+
+/// Check a new entry's `file_size` against the [`StorageQuota`] in the DNA properties
+///
+/// The author's previous entries of the same type are summed using `size_of`, which scans the
+/// author's chain (see [`sum_agent_entry_sizes`]).  That scan is skipped unless
+/// `max_agent_storage` is set.  Malformed DNA properties make every entry invalid rather than
+/// silently disabling the quota.
+pub fn check_storage_quota<F>(
+    create: &Create,
+    file_size: u64,
+    size_of: F,
+) -> ExternResult<ValidateCallbackResult>
+where
+    F: Fn(Entry) -> ExternResult<u64>,
+{
+    let quota = match DevHubProperties::get() {
+        Ok(properties) => properties.storage_quota,
+        Err(err) => invalid!(format!(
+            "Cannot check storage quota: {:?}", err,
+        )),
+    };
+
+    if let Some(max_file_size) = quota.max_file_size {
+        if file_size > max_file_size {
+            invalid!(format!(
+                "File size exceeds the maximum allowed by the storage quota: {} > {}",
+                file_size, max_file_size,
+            ))
+        }
+    }
+
+    if let Some(max_agent_storage) = quota.max_agent_storage {
+        if create.action_seq > MAX_QUOTA_CHAIN_SCAN {
+            invalid!(format!(
+                "Agent storage quota cannot be verified for chains longer than {} actions",
+                MAX_QUOTA_CHAIN_SCAN,
+            ))
+        }
+
+        let used = sum_agent_entry_sizes(
+            &create.author, &create.prev_action, &create.entry_type, size_of,
+        )?;
+
+        if used.saturating_add( file_size ) > max_agent_storage {
+            invalid!(format!(
+                "Agent storage quota exceeded: {} used + {} > {}",
+                used, file_size, max_agent_storage,
+            ))
+        }
+    }
+
+    valid!()
+}
@@ -0,0 +1,88 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+};
+use std::str;
+use hdi::prelude::*;
+use hdi_extensions::{
+    guest_error,
+    // Macros
+    valid, invalid,
+};
+
+
+/// Parse a link tag as a semver version (eg. a package version tag)
+pub fn parse_version_tag(tag: &LinkTag) -> ExternResult<semver::Version> {
+    let vtag = str::from_utf8( &tag.0 )
+        .map_err( |err| guest_error!(format!(
+            "Version tag is not valid UTF-8: {:?}", err,
+        )) )?;
+
+    semver::Version::parse( vtag )
+        .map_err( |err| guest_error!(format!(
+            "Version tag '{}' is not a valid semver: {}", vtag, err,
+        )) )
+}
+
+
+/// Ensure that the link author has not already claimed the link's tag from the same base for
+/// another target (eg. a version tag of a package)
+///
+/// Uniqueness is per agent only.  Validation can only read the author's own chain (up to the
+/// link's previous action), so 2 maintainers of a group can both claim the same tag.  Readers
+/// must resolve duplicate claims to the oldest link (see `devhub_sdk::PackageBase`).  Links that
+/// the author has since deleted are ignored.
+pub fn check_tag_unclaimed(
+    create: &CreateLink,
+) -> ExternResult<ValidateCallbackResult> {
+    let activity = must_get_agent_activity(
+        create.author.to_owned(),
+        ChainFilter::new( create.prev_action.to_owned() ),
+    )?;
+
+    let deleted_links : Vec<ActionHash> = activity.iter()
+        .filter_map( |activity| match &activity.action.hashed.content {
+            Action::DeleteLink(delete_link) => Some( delete_link.link_add_address.to_owned() ),
+            _ => None,
+        })
+        .collect();
+
+    for activity in activity.iter() {
+        if let Action::CreateLink(prev_create) = &activity.action.hashed.content {
+            if prev_create.base_address == create.base_address
+                && prev_create.zome_index == create.zome_index
+                && prev_create.link_type == create.link_type
+                && prev_create.tag == create.tag
+                && prev_create.target_address != create.target_address
+                && !deleted_links.contains( &activity.action.hashed.hash )
+            {
+                invalid!(format!(
+                    "Tag {:?} is already claimed by {} from base {} (claimed by {})",
+                    create.tag, prev_create.target_address, create.base_address, create.author,
+                ))
+            }
+        }
+    }
+
+    valid!()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_semver_tag() {
+        let version = parse_version_tag( &LinkTag::new( "1.2.3-beta.1" ) ).unwrap();
+
+        assert_eq!( ( version.major, version.minor, version.patch ), ( 1, 2, 3 ) );
+        assert_eq!( version.pre.as_str(), "beta.1" );
+    }
+
+    #[test]
+    fn parse_invalid_tags() {
+        assert!( parse_version_tag( &LinkTag::new( "1.2" ) ).is_err() );
+        assert!( parse_version_tag( &LinkTag::new( vec![ 0xff, 0xfe ] ) ).is_err() );
+    }
+}
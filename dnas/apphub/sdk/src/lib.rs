@@ -24,6 +24,7 @@ use apphub_types::{
     RmpvValue,
     ResourcesMap,
     WebAppResourcesMap,
+    HRL,
 
     RoleToken,
     AppToken,
@@ -36,6 +37,7 @@ use apphub_types::{
 
     WebAppPackageEntry,
     WebAppPackageVersionEntry,
+    BuildProvenance,
 
     UiEntry,
     mere_memory_types,
@@ -46,6 +48,12 @@ use mere_memory_types::{
 use dnahub_sdk::{
     DnaTokenInput,
     DnaAsset,
+    IngestedDna,
+    DNAHUB_ROLE_NAME,
+    zomehub_sdk::save_zomehub_memory,
+};
+use dnahub_types::{
+    DnaEntry,
 };
 use hc_crud::{
     Entity, EntityId,
@@ -152,6 +160,9 @@ pub struct CreateAppInput {
     pub resources: ResourcesMap,
     pub roles_dna_tokens: RolesDnaTokensInput,
     pub claimed_file_size: u64,
+    /// Reject the App unless it passes [`verify_app_entry`]
+    #[serde(default)]
+    pub strict: bool,
 }
 
 impl TryFrom<CreateAppInput> for AppEntry {
@@ -207,6 +218,9 @@ impl From<WebAppEntryInput> for WebAppEntry {
 pub struct CreateWebAppInput {
     pub manifest: RmpvValue,
     pub resources: WebAppResourcesMap,
+    /// Reject the WebApp unless it passes [`verify_webapp_entry`]
+    #[serde(default)]
+    pub strict: bool,
 }
 
 impl TryFrom<CreateWebAppInput> for WebAppEntry {
@@ -289,6 +303,8 @@ pub struct WebAppPackageVersionEntryInput {
     pub changelog: Option<String>,
     pub source_code_revision_uri: Option<String>,
     #[serde(default)]
+    pub build_provenance: Option<BuildProvenance>,
+    #[serde(default)]
     pub metadata: BTreeMap<String, RmpvValue>,
 }
 
@@ -301,6 +317,7 @@ impl From<WebAppPackageVersionEntryInput> for WebAppPackageVersionEntry {
             changelog: input.changelog,
             maintainer: input.maintainer,
             source_code_revision_uri: input.source_code_revision_uri,
+            build_provenance: input.build_provenance,
             metadata: input.metadata,
         }
     }
@@ -319,6 +336,7 @@ pub struct CreateWebAppPackageVersionInput {
     pub changelog: Option<String>,
     pub maintainer: Option<Authority>,
     pub source_code_revision_uri: Option<String>,
+    pub build_provenance: Option<BuildProvenance>,
 }
 
 impl TryFrom<CreateWebAppPackageVersionInput> for WebAppPackageVersionEntry {
@@ -336,6 +354,7 @@ impl TryFrom<CreateWebAppPackageVersionInput> for WebAppPackageVersionEntry {
                 maintainer: input.maintainer
                     .unwrap_or( agent_id()?.into() ),
                 source_code_revision_uri: input.source_code_revision_uri,
+                build_provenance: input.build_provenance,
                 metadata: input.metadata,
             }
         )
@@ -381,6 +400,27 @@ impl TryInto<AppAsset> for EntryHash {
     }
 }
 
+impl AppAsset {
+    /// Assemble the installable `.happ` bundle bytes
+    pub fn to_bundle_bytes(&self) -> ExternResult<Vec<u8>> {
+        let mut resources = BundleResources::new();
+
+        for role_manifest in self.app_entry.deserialized_manifest()?.roles.iter() {
+            let dna_asset = self.dna_assets.get( &role_manifest.name )
+                .ok_or(guest_error!(format!(
+                    "AppAsset is missing DNA asset for role '{}'", role_manifest.name,
+                )))?;
+
+            resources.insert(
+                role_manifest.dna.bundled.to_owned(),
+                dna_asset.to_bundle_bytes()?,
+            );
+        }
+
+        encode_bundle( &self.app_entry.manifest, resources )
+    }
+}
+
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WebAppAsset {
@@ -418,6 +458,28 @@ impl TryInto<WebAppAsset> for EntryHash {
     }
 }
 
+impl WebAppAsset {
+    /// Assemble the installable `.webhapp` bundle bytes
+    pub fn to_bundle_bytes(&self) -> ExternResult<Vec<u8>> {
+        let manifest = self.webapp_entry.deserialized_manifest()?;
+        let mut resources = BundleResources::new();
+
+        resources.insert(
+            manifest.happ_manifest.bundled,
+            self.app_asset.to_bundle_bytes()?,
+        );
+        resources.insert(
+            manifest.ui.bundled,
+            decompress_memory_bytes(
+                &self.ui_asset.memory_entry.compression,
+                self.ui_asset.bytes.clone(),
+            )?,
+        );
+
+        encode_bundle( &self.webapp_entry.manifest, resources )
+    }
+}
+
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MemoryWithBytes(
@@ -453,3 +515,563 @@ impl TryInto<UiAsset> for EntryHash {
         )
     }
 }
+
+
+/// Verification result for a single role in an App manifest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoleVerification {
+    pub name: RoleName,
+    pub resource: Option<HRL>,
+    /// The role token stored in `AppEntry.app_token`
+    pub claimed_token: Option<RoleToken>,
+    /// The role token recomputed from the `DnaEntry` in dnahub
+    pub actual_token: Option<RoleToken>,
+    pub errors: Vec<String>,
+}
+
+impl RoleVerification {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppVerificationReport {
+    /// Errors that are not specific to a role (eg. the app token hashes are inconsistent)
+    pub errors: Vec<String>,
+    pub roles: Vec<RoleVerification>,
+    pub valid: bool,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UiVerification {
+    pub ui_entry: Option<UiEntry>,
+    pub errors: Vec<String>,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WebAppVerificationReport {
+    /// Errors that are not specific to the app or UI (eg. the webapp token does not match)
+    pub errors: Vec<String>,
+    pub app: Option<AppVerificationReport>,
+    pub ui: UiVerification,
+    pub valid: bool,
+}
+
+
+/// Recompute each role's token from the real `DnaEntry` and compare it to the claimed token
+///
+/// The `roles_dna_tokens` used to create an `AppEntry` come from the client, so this is the only
+/// way to prove that the `app_token` belongs to the DNAs in `resources`.
+pub fn verify_app_entry(app_entry: &AppEntry) -> ExternResult<AppVerificationReport> {
+    let manifest = app_entry.deserialized_manifest()?;
+    let mut errors = vec![];
+    let mut roles = vec![];
+
+    if let Err(err) = app_entry.validate_roles_token() {
+        errors.push(format!("{:?}", err ));
+    }
+
+    match app_entry.calculate_app_token() {
+        Ok(app_token) if app_token != app_entry.app_token => errors.push(
+            "App token hashes do not match the roles token".to_string()
+        ),
+        Ok(_) => (),
+        Err(err) => errors.push(format!("{:?}", err )),
+    }
+
+    for role_manifest in manifest.roles.iter() {
+        let mut report = RoleVerification {
+            name: role_manifest.name.clone(),
+            resource: app_entry.resources.get( &role_manifest.dna.bundled ).cloned(),
+            claimed_token: app_entry.app_token.roles_token.role_token( &role_manifest.name ).ok(),
+            actual_token: None,
+            errors: vec![],
+        };
+
+        if report.claimed_token.is_none() {
+            report.errors.push(format!(
+                "Missing RoleToken for role '{}'", role_manifest.name,
+            ));
+        }
+
+        let hrl = match &report.resource {
+            Some(hrl) => hrl.clone(),
+            None => {
+                report.errors.push(format!(
+                    "AppEntry does not have resource for path '{}'", role_manifest.dna.bundled,
+                ));
+                roles.push( report );
+                continue;
+            },
+        };
+
+        let dna_entry = match call_cell(
+            hrl.dna.clone(),
+            "dnahub_csr",
+            "get_dna_entry",
+            hrl.target.clone(),
+            (),
+        ) {
+            Ok(entity) => {
+                let entity : Entity<DnaEntry> = entity;
+                entity.content
+            },
+            Err(err) => {
+                report.errors.push(format!(
+                    "Failed to get DNA entry for resource '{}' ({}): {:?}",
+                    role_manifest.dna.bundled, hrl.target, err,
+                ));
+                roles.push( report );
+                continue;
+            },
+        };
+
+        let dna_token = match dna_entry.calc_dna_token() {
+            Ok(dna_token) => dna_token,
+            Err(err) => {
+                report.errors.push(format!(
+                    "Failed to calculate DNA token for DNA entry ({}): {:?}", hrl.target, err,
+                ));
+                roles.push( report );
+                continue;
+            },
+        };
+
+        if dna_token != dna_entry.dna_token {
+            report.errors.push(format!(
+                "DNA entry ({}) token does not match its manifest", hrl.target,
+            ));
+        }
+
+        let actual_token = match RoleToken::new( &dna_token, &role_manifest.dna.modifiers ) {
+            Ok(role_token) => role_token,
+            Err(err) => {
+                report.errors.push(format!(
+                    "Failed to calculate RoleToken for role '{}': {:?}", role_manifest.name, err,
+                ));
+                roles.push( report );
+                continue;
+            },
+        };
+
+        if let Some(claimed_token) = &report.claimed_token {
+            if *claimed_token != actual_token {
+                report.errors.push(format!(
+                    "RoleToken does not match DNA entry ({})", hrl.target,
+                ));
+            }
+        }
+
+        report.actual_token = Some( actual_token );
+        roles.push( report );
+    }
+
+    let valid = errors.is_empty() && roles.iter().all( |role| role.is_valid() );
+
+    Ok(
+        AppVerificationReport {
+            errors,
+            roles,
+            valid,
+        }
+    )
+}
+
+
+fn verify_ui_entry(addr: &EntryHash) -> UiVerification {
+    let mut report = UiVerification {
+        ui_entry: None,
+        errors: vec![],
+    };
+
+    let ui_entry : UiEntry = match must_get( addr ).and_then( |record| record.try_into() ) {
+        Ok(ui_entry) => ui_entry,
+        Err(err) => {
+            report.errors.push(format!(
+                "Failed to get UI entry ({}): {:?}", addr, err,
+            ));
+            return report;
+        },
+    };
+
+    match must_get_entry( ui_entry.mere_memory_addr.clone() )
+        .and_then( |entry| MemoryEntry::try_from( entry.content ) )
+    {
+        Ok(memory) => {
+            let size = memory.uncompressed_size.unwrap_or( memory.memory_size );
+
+            if size != ui_entry.file_size {
+                report.errors.push(format!(
+                    "UI file size ({}) does not match memory size ({})", ui_entry.file_size, size,
+                ));
+            }
+        },
+        Err(err) => report.errors.push(format!(
+            "Failed to get UI memory ({}): {:?}", ui_entry.mere_memory_addr, err,
+        )),
+    }
+
+    report.ui_entry = Some( ui_entry );
+
+    report
+}
+
+
+/// Verify the webapp token and recurse into the app and UI entries
+pub fn verify_webapp_entry(webapp_entry: &WebAppEntry) -> ExternResult<WebAppVerificationReport> {
+    let mut errors = vec![];
+
+    match webapp_entry.calculate_webapp_token() {
+        Ok(webapp_token) if webapp_token != webapp_entry.webapp_token => errors.push(
+            "WebApp token does not match the app and UI entries".to_string()
+        ),
+        Ok(_) => (),
+        Err(err) => errors.push(format!("{:?}", err )),
+    }
+
+    let app_entry_addr = webapp_entry.app_entry_addr()?;
+    let app = match must_get( &app_entry_addr ).and_then( |record| record.try_into() ) {
+        Ok(app_entry) => {
+            let app_entry : AppEntry = app_entry;
+            Some( verify_app_entry( &app_entry )? )
+        },
+        Err(err) => {
+            errors.push(format!(
+                "Failed to get App entry ({}): {:?}", app_entry_addr, err,
+            ));
+            None
+        },
+    };
+
+    let ui = verify_ui_entry( &webapp_entry.ui_entry_addr()? );
+
+    let valid = errors.is_empty()
+        && app.as_ref().is_some_and( |app| app.valid )
+        && ui.errors.is_empty();
+
+    Ok(
+        WebAppVerificationReport {
+            errors,
+            app,
+            ui,
+            valid,
+        }
+    )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateUiEntryInput {
+    pub mere_memory_addr: EntryHash,
+}
+
+
+/// Get a memory and its (decompressed) bytes from this cell's mere_memory
+pub fn get_memory(addr: &EntryHash) -> ExternResult<(MemoryEntry, Vec<u8>)> {
+    let memory_with_bytes : MemoryWithBytes = call_zome(
+        "mere_memory_api",
+        "get_memory_with_bytes",
+        addr.to_owned(),
+        (),
+    )?;
+    let bytes = decompress_memory_bytes( &memory_with_bytes.0.compression, memory_with_bytes.1 )?;
+
+    Ok(( memory_with_bytes.0, bytes ))
+}
+
+
+/// The entities created (or reused) when ingesting a `.happ` bundle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestedApp {
+    pub app_entry: Entity<AppEntry>,
+    /// Ingested DNAs keyed by role name
+    pub dnas: BTreeMap<RoleName, IngestedDna>,
+}
+
+
+/// The entities created (or reused) when ingesting a `.webhapp` bundle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestedWebApp {
+    pub webapp_entry: Entity<WebAppEntry>,
+    pub app: IngestedApp,
+    pub ui_entry: Entity<UiEntry>,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+#[serde(rename_all = "snake_case")]
+pub enum IngestedBundle {
+    Dna(IngestedDna),
+    Happ(IngestedApp),
+    // Boxed because it is much larger than the other variants; serialization is unchanged
+    Webhapp(Box<IngestedWebApp>),
+}
+
+
+/// DnaHub reads the bundle from ZomeHub's mere_memory, so the bytes are saved there first
+fn ingest_dna_bytes(bytes: &[u8]) -> ExternResult<IngestedDna> {
+    call_role(
+        DNAHUB_ROLE_NAME,
+        "dnahub_csr",
+        "ingest_dna",
+        save_zomehub_memory( bytes )?,
+        (),
+    )
+}
+
+
+fn ingest_happ(manifest: RmpvValue, resources: BundleResources, claimed_file_size: u64) ->
+    ExternResult<IngestedApp>
+{
+    let app_manifest = AppEntry::deserialize_manifest( &manifest )?;
+    let mut hrls = ResourcesMap::new();
+    let mut roles_dna_tokens = BTreeMap::new();
+    let mut dnas = BTreeMap::new();
+
+    for role_manifest in app_manifest.roles.iter() {
+        let rpath = &role_manifest.dna.bundled;
+        let dna_bytes = resources.get( rpath )
+            .ok_or(guest_error!(format!(
+                "hApp bundle does not have resource for path '{}'", rpath,
+            )))?;
+        let ingested = ingest_dna_bytes( dna_bytes )?;
+        let dna_token = &ingested.dna_entry.content.dna_token;
+
+        hrls.insert( rpath.to_owned(), HRL {
+            dna: ingested.dna.clone(),
+            target: ingested.dna_entry.address.clone().into(),
+        });
+        roles_dna_tokens.insert( role_manifest.name.clone(), DnaTokenInput {
+            integrity_hash: ByteBuf::from( dna_token.integrity_hash.clone() ),
+            integrities_token_hash: ByteBuf::from( dna_token.integrities_token_hash.clone() ),
+            coordinators_token_hash: ByteBuf::from( dna_token.coordinators_token_hash.clone() ),
+        });
+        dnas.insert( role_manifest.name.clone(), ingested );
+    }
+
+    let app_entry : Entity<AppEntry> = call_zome(
+        "apphub_csr",
+        "create_app",
+        CreateAppInput {
+            manifest,
+            resources: hrls,
+            roles_dna_tokens: RolesDnaTokensInput( roles_dna_tokens ),
+            claimed_file_size,
+            strict: false,
+        },
+        (),
+    )?;
+
+    Ok(
+        IngestedApp {
+            app_entry,
+            dnas,
+        }
+    )
+}
+
+
+fn ingest_webhapp(manifest: RmpvValue, resources: BundleResources) -> ExternResult<IngestedWebApp> {
+    let webapp_manifest = WebAppEntry::deserialize_manifest( &manifest )?;
+    let happ_rpath = webapp_manifest.happ_manifest.bundled;
+    let ui_rpath = webapp_manifest.ui.bundled;
+
+    let happ_bytes = resources.get( &happ_rpath )
+        .ok_or(guest_error!(format!(
+            "WebApp bundle does not have resource for path '{}'", happ_rpath,
+        )))?;
+    let ui_bytes = resources.get( &ui_rpath )
+        .ok_or(guest_error!(format!(
+            "WebApp bundle does not have resource for path '{}'", ui_rpath,
+        )))?;
+
+    let (happ_manifest, happ_resources) = decode_bundle( happ_bytes )?;
+    let app = ingest_happ( happ_manifest, happ_resources, happ_bytes.len() as u64 )?;
+
+    let mere_memory_addr : EntryHash = call_zome(
+        "mere_memory_api",
+        "save_bytes",
+        ByteBuf::from( ui_bytes.to_owned() ),
+        (),
+    )?;
+    let ui_entry : Entity<UiEntry> = call_zome(
+        "apphub_csr",
+        "create_ui",
+        CreateUiEntryInput {
+            mere_memory_addr,
+        },
+        (),
+    )?;
+
+    let webapp_resources = WebAppResourcesMap::from([
+        ( happ_rpath, app.app_entry.address.clone() ),
+        ( ui_rpath, ui_entry.address.clone() ),
+    ]);
+    let webapp_entry : Entity<WebAppEntry> = call_zome(
+        "apphub_csr",
+        "create_webapp",
+        CreateWebAppInput {
+            manifest,
+            resources: webapp_resources,
+            strict: false,
+        },
+        (),
+    )?;
+
+    Ok(
+        IngestedWebApp {
+            webapp_entry,
+            app,
+            ui_entry,
+        }
+    )
+}
+
+
+/// Unpack `.dna`, `.happ` or `.webhapp` bundle bytes and create (or reuse) every entry in the tree
+///
+/// The bundle type is detected from the manifest.  This must run in the AppHub cell; DNAs are
+/// ingested in the `dnahub` role, which ingests zomes in the `zomehub` role.  `claimed_file_size`
+/// is the size of the bundle file that `bytes` came from.
+pub fn ingest_bundle(bytes: &[u8], claimed_file_size: u64) -> ExternResult<IngestedBundle> {
+    let (manifest, resources) = decode_bundle( bytes )?;
+
+    if manifest_has_field( &manifest, "happ_manifest" ) {
+        Ok( IngestedBundle::Webhapp( Box::new( ingest_webhapp( manifest, resources )? ) ) )
+    }
+    else if manifest_has_field( &manifest, "roles" ) {
+        Ok( IngestedBundle::Happ( ingest_happ( manifest, resources, claimed_file_size )? ) )
+    }
+    else if manifest_has_field( &manifest, "integrity" ) {
+        Ok( IngestedBundle::Dna( ingest_dna_bytes( bytes )? ) )
+    }
+    else {
+        Err(guest_error!(format!(
+            "Unknown bundle type; manifest does not match a DNA, hApp or WebApp"
+        )))
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiffAppEntriesInput {
+    pub from: EntryHash,
+    pub to: EntryHash,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiffWebAppPackageVersionsInput {
+    pub from: EntityId,
+    pub to: EntityId,
+}
+
+
+/// How a role present in both apps differs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoleDiff {
+    pub name: String,
+    /// The DNA integrity hash or integrity zomes changed (network-breaking)
+    pub integrity_changed: bool,
+    /// The coordinator zomes changed (hot-swappable)
+    pub coordinators_changed: bool,
+    /// The DNA modifiers changed (network-breaking)
+    pub modifiers_changed: bool,
+}
+
+impl RoleDiff {
+    pub fn is_network_breaking(&self) -> bool {
+        self.integrity_changed || self.modifiers_changed
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppEntryDiff {
+    pub added_roles: Vec<String>,
+    pub removed_roles: Vec<String>,
+    /// Only the roles with at least one change
+    pub changed_roles: Vec<RoleDiff>,
+    /// True when both apps have the same `AppToken.integrity_hash`
+    pub network_compatible: bool,
+    /// True when no migration is needed (ie. any differences are limited to coordinators)
+    pub coordinator_update: bool,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WebAppDiff {
+    pub app: AppEntryDiff,
+    pub ui_changed: bool,
+}
+
+
+/// Compare two app tokens role by role
+pub fn diff_app_tokens(from: &AppToken, to: &AppToken) -> AppEntryDiff {
+    let from_roles : BTreeMap<&String, &RoleToken> = from.roles_token.0.iter()
+        .map( |(name, role_token)| ( name, role_token ) )
+        .collect();
+    let to_roles : BTreeMap<&String, &RoleToken> = to.roles_token.0.iter()
+        .map( |(name, role_token)| ( name, role_token ) )
+        .collect();
+
+    let mut added_roles = vec![];
+    let mut changed_roles = vec![];
+
+    for (name, to_token) in to_roles.iter() {
+        let from_token = match from_roles.get( name ) {
+            Some(role_token) => role_token,
+            None => {
+                added_roles.push( name.to_string() );
+                continue;
+            },
+        };
+        let role_diff = RoleDiff {
+            name: name.to_string(),
+            integrity_changed: from_token.integrity_hash != to_token.integrity_hash
+                || from_token.integrities_token_hash != to_token.integrities_token_hash,
+            coordinators_changed: from_token.coordinators_token_hash != to_token.coordinators_token_hash,
+            modifiers_changed: from_token.modifiers_hash != to_token.modifiers_hash,
+        };
+
+        if role_diff.integrity_changed
+            || role_diff.coordinators_changed
+            || role_diff.modifiers_changed
+        {
+            changed_roles.push( role_diff );
+        }
+    }
+
+    let removed_roles : Vec<String> = from_roles.keys()
+        .filter( |name| !to_roles.contains_key( *name ) )
+        .map( |name| name.to_string() )
+        .collect();
+
+    let network_compatible = from.integrity_hash == to.integrity_hash;
+    let coordinator_update = network_compatible
+        && added_roles.is_empty()
+        && removed_roles.is_empty()
+        && !changed_roles.iter().any( |role_diff| role_diff.is_network_breaking() );
+
+    AppEntryDiff {
+        added_roles,
+        removed_roles,
+        changed_roles,
+        network_compatible,
+        coordinator_update,
+    }
+}
+
+
+/// Compare two webapp tokens; the hApp part is compared with [`diff_app_tokens`]
+pub fn diff_webapp_tokens(from: &WebAppToken, to: &WebAppToken) -> WebAppDiff {
+    WebAppDiff {
+        app: diff_app_tokens( &from.app_token, &to.app_token ),
+        ui_changed: from.ui_hash != to.ui_hash,
+    }
+}
@@ -9,6 +9,16 @@ pub use hdi_extensions;
 pub use hdi_extensions::hdi;
 pub use holochain_types::*;
 pub use mere_memory_types;
+pub use devhub_types;
+pub use devhub_types::Authority;
+pub use devhub_types::{
+    ReviewEntry,
+    ReviewVerdict,
+    ReviewSummary,
+    MAX_SECURITY_SCORE,
+    BuildProvenance,
+    RebuildAttestationEntry,
+};
 
 pub use app_entry::*;
 pub use ui_entry::*;
@@ -179,28 +189,38 @@ pub struct AppToken {
     pub roles_token: RolesToken,
 }
 
+impl AppToken {
+    /// Path anchor for finding App entries with the same token
+    pub fn anchor_path(&self) -> Path {
+        Path::from( vec![
+            Component::from("app_token"),
+            Component::from( self.integrity_hash.clone() ),
+            Component::from( self.roles_token_hash.clone() ),
+        ])
+    }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-pub struct WebAppToken {
-    pub ui_hash: Vec<u8>,
-    pub app_token: AppToken,
+    /// Path anchor for finding network compatible App entries and versions
+    pub fn integrity_anchor_path(&self) -> Path {
+        integrity_hash_anchor_path( &self.integrity_hash )
+    }
 }
 
 
+pub fn integrity_hash_anchor_path(integrity_hash: &[u8]) -> Path {
+    Path::from( vec![
+        Component::from("integrity_hash"),
+        Component::from( integrity_hash.to_vec() ),
+    ])
+}
+
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-#[serde(tag = "type", content = "content")]
-#[serde(rename_all = "snake_case")]
-pub enum Authority {
-    // Group(ActionHash, ActionHash),
-    Agent(AgentPubKey),
+pub struct WebAppToken {
+    pub ui_hash: Vec<u8>,
+    pub app_token: AppToken,
 }
 
-impl From<AgentPubKey> for Authority {
-    fn from(agent_pub_key: AgentPubKey) -> Self {
-        Authority::Agent(agent_pub_key)
-    }
-}
+
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct DeprecationNotice {
@@ -26,4 +26,20 @@ impl UiEntry {
 
         Ok( entry )
     }
+
+    pub fn memory_hash(&self) -> ExternResult<String> {
+        let memory : MemoryEntry = must_get_entry( self.mere_memory_addr.clone() )?.content.try_into()?;
+
+        Ok( memory.hash )
+    }
+
+    /// Path anchor for finding UI entries with the same memory content
+    pub fn hash_anchor_path(&self) -> ExternResult<Path> {
+        Ok( ui_hash_anchor_path( &self.memory_hash()? ) )
+    }
+}
+
+
+pub fn ui_hash_anchor_path(hash: &str) -> Path {
+    Path::from( format!("ui_hash.{}", hash ) )
 }
@@ -5,6 +5,7 @@ use hdi::prelude::*;
 use crate::{
     MemoryAddr,
     Authority,
+    devhub_types::Maintained,
     DeprecationNotice,
 };
 
@@ -36,3 +37,9 @@ pub struct WebAppPackageEntry {
     // Common fields
     pub metadata: BTreeMap<String, rmpv::Value>,
 }
+
+impl Maintained for WebAppPackageEntry {
+    fn maintainer(&self) -> &Authority {
+        &self.maintainer
+    }
+}
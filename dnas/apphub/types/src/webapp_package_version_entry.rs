@@ -3,6 +3,7 @@ use crate::{
     EntityId, BundleAddr,
     Authority,
     WebAppToken,
+    BuildProvenance,
 };
 #[allow(unused)]
 use crate::WebAppEntry;
@@ -31,6 +32,9 @@ pub struct WebAppPackageVersionEntry {
     // Optional
     pub changelog: Option<String>,
     pub source_code_revision_uri: Option<String>,
+    /// How the UI was built; independent rebuilds are recorded as `RebuildAttestationEntry`s
+    #[serde(default)]
+    pub build_provenance: Option<BuildProvenance>,
 
     // Common fields
     pub metadata: BTreeMap<String, rmpv::Value>,
@@ -9,6 +9,7 @@ import {
     CellZomelets,
 }					from '@spartan-hc/zomelets'; // approx. 7kb
 import { Bundle }			from '@spartan-hc/bundles'; // approx. 39kb
+import { CoopContentZomelet }		from '@spartan-hc/coop-content-zomelets';
 import { // Relative import is causing duplicates (holo-hash, zomelets, bundles)
     DnaHubCSRZomelet,
     ZomeHubCSRZomelet,
@@ -33,6 +34,8 @@ import {
     AppAsset,
     UiAsset,
     WebAppAsset,
+    Review,
+    RebuildAttestation,
 }					from './types.js';
 
 
@@ -75,6 +78,16 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return new App( result, this );
     },
+    async get_app_entries_by_token ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( entry => new App( entry, this ) );
+    },
+    async get_apps_with_integrity_hash ( input ) {
+	const result			= await this.call( new Uint8Array( input ) );
+
+	return result.map( entry => new App( entry, this ) );
+    },
     async get_app_asset ( input ) {
 	const result			= await this.call( new EntryHash( input ) );
 	const app_asset			= AppAsset( result );
@@ -118,12 +131,53 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return app_asset;
     },
+    async ingest_bundle ( input ) {
+	const result			= await this.call( new EntryHash( input ) );
+	const content			= result.content;
+
+	switch ( result.type ) {
+	    case "webhapp":
+		content.webapp_entry	= new WebApp( content.webapp_entry, this );
+		content.ui_entry	= new Ui( content.ui_entry, this );
+		content.app.app_entry	= new App( content.app.app_entry, this );
+		break;
+	    case "happ":
+		content.app_entry	= new App( content.app_entry, this );
+		break;
+	}
+
+	return result;
+    },
+    async verify_app ( input ) {
+	return await this.call( new EntryHash( input ) );
+    },
+    async diff_app_entries ( input ) {
+	return await this.call({
+	    "from": new EntryHash( input.from ),
+	    "to": new EntryHash( input.to ),
+	});
+    },
     async get_app_entries_for_agent ( input ) {
 	const agent_id			= input ? new AgentPubKey( input ) : input;
 	const entries			= await this.call( agent_id );
 
 	return entries.map( entry => new App( entry, this ) );
     },
+    async get_dependents ( input ) {
+	const entries			= await this.call( new AnyDhtHash( input ) );
+
+	return entries.map( entry => new App( entry, this ) );
+    },
+    async get_app_entries_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new App( entry, this ) );
+
+	return result;
+    },
     async delete_app ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
@@ -148,6 +202,11 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return new Ui( result, this );
     },
+    async get_ui_entries_by_hash ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( entry => new Ui( entry, this ) );
+    },
     async get_ui_asset ( input ) {
 	const result			= await this.call( new EntryHash( input ) );
 
@@ -178,6 +237,23 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return entries.map( entry => new Ui( entry, this ) );
     },
+    async get_ui_entries_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new Ui( entry, this ) );
+
+	return result;
+    },
+    async get_agent_storage_usage ( input ) {
+	const result			= await this.call( input ? new AgentPubKey( input ) : null );
+
+	result.agent			= new AgentPubKey( result.agent );
+
+	return result;
+    },
     async delete_ui ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
@@ -251,12 +327,25 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return webapp_asset;
     },
+    async verify_webapp ( input ) {
+	return await this.call( new EntryHash( input ) );
+    },
     async get_webapp_entries_for_agent ( input ) {
 	const agent_id			= input ? new AgentPubKey( input ) : input;
 	const entries			= await this.call( agent_id );
 
 	return entries.map( entry => new WebApp( entry, this ) );
     },
+    async get_webapp_entries_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new WebApp( entry, this ) );
+
+	return result;
+    },
     async delete_webapp ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
@@ -269,8 +358,17 @@ export const AppHubCSRZomelet		= new Zomelet({
 	input.icon			= await this.zomes.mere_memory_api.save( input.icon );
 
 	const result			= await this.call( input );
+        const webapp_package            = new WebAppPackage( result, this );
 
-	return new WebAppPackage( result, this );
+        if ( webapp_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_link({
+                "group_id": webapp_package.maintainer.content[0],
+                "content_target": webapp_package.$id,
+                "content_type": "webapp_package",
+            });
+        }
+
+	return webapp_package;
     },
     async create_webapp_package_entry ( input ) {
 	this.log.trace("Create WebApp package entry input:", input );
@@ -298,22 +396,96 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	return version_map;
     },
+    async get_webapp_package_versions_paginated ( input ) {
+	const result			= await this.call({
+	    "input": new ActionHash( input.input ),
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( ([ vtag, pack_version ]) => {
+	    const version		= new WebAppPackageVersion( pack_version, this );
+	    version.version		= vtag;
+
+	    return [ vtag, version ];
+	});
+
+	return result;
+    },
     async get_all_webapp_packages ( input ) {
 	const entries			= await this.call(); // new AgentPubKey( input )
 
 	return entries.map( entity => new WebAppPackage( entity, this ) );
     },
+    async get_all_webapp_packages_paginated ( input = {} ) {
+	const result			= await this.call( input );
+
+	result.items			= result.items.map( entity => new WebAppPackage( entity, this ) );
+
+	return result;
+    },
+    async search_webapp_packages_by_name_prefix ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entity => new WebAppPackage( entity, this ) );
+    },
+    async search_webapp_packages_by_name_fuzzy ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entity => new WebAppPackage( entity, this ) );
+    },
     async update_webapp_package ( input ) {
 	if ( input.icon && input.icon.length > 39 )
 	    input.icon			= await this.zomes.mere_memory_api.save( input.icon );
 
+        if ( input.properties.maintainer === undefined ) {
+            const prev_webapp_pack      = await this.functions.get_webapp_package_entry( input.base );
+            input.properties.maintainer = prev_webapp_pack.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
 	this.log.trace("Update WebApp package input:", input );
 	const result			= await this.call( input );
+        const webapp_package            = new WebAppPackage( result, this );
 
-	return new WebAppPackage( result, this );
+        if ( webapp_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": webapp_package.maintainer.content[0],
+                "content_id": webapp_package.$id,
+                "content_prev": input.base,
+                "content_next": webapp_package.$action,
+            });
+        }
+
+	return webapp_package;
     },
     async deprecate_webapp_package ( input ) {
-	return await this.call( input );
+        if ( input.properties.maintainer === undefined ) {
+            const prev_webapp_pack      = await this.functions.get_webapp_package_entry( input.base );
+
+            if ( prev_webapp_pack.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( prev_webapp_pack.maintainer.content[0] );
+                prev_webapp_pack.maintainer.content[1] = group.$action;
+                input.properties.maintainer = prev_webapp_pack.maintainer;
+            }
+        }
+
+	const result			= await this.call( input );
+        const webapp_package            = new WebAppPackage( result, this );
+
+        if ( webapp_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": webapp_package.maintainer.content[0],
+                "content_id": webapp_package.$id,
+                "content_prev": input.base,
+                "content_next": webapp_package.$action,
+            });
+        }
+
+	return webapp_package;
     },
     async delete_webapp_package ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
@@ -367,7 +539,17 @@ export const AppHubCSRZomelet		= new Zomelet({
 
 	result.content.version		= input.version;
 
-	return new WebAppPackageVersion( result, this );
+        const webapp_package_version    = new WebAppPackageVersion( result, this );
+
+        if ( webapp_package_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_link({
+                "group_id": webapp_package_version.maintainer.content[0],
+                "content_target": webapp_package_version.$id,
+                "content_type": "webapp_package_version",
+            });
+        }
+
+	return webapp_package_version;
     },
     async create_webapp_package_version_entry ( input ) {
 	const result			= await this.call( input );
@@ -375,10 +557,30 @@ export const AppHubCSRZomelet		= new Zomelet({
 	return new WebAppPackageVersion( result, this );
     },
     async update_webapp_package_version ( input ) {
+        if ( input.properties.maintainer === undefined ) {
+            const prev_version          = await this.functions.get_webapp_package_version_entry( input.base );
+            input.properties.maintainer = prev_version.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
 	this.log.trace("Update WebApp package versioninput:", input );
 	const result			= await this.call( input );
+        const webapp_package_version    = new WebAppPackageVersion( result, this );
 
-	return new WebAppPackageVersion( result, this );
+        if ( webapp_package_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": webapp_package_version.maintainer.content[0],
+                "content_id": webapp_package_version.$id,
+                "content_prev": input.base,
+                "content_next": webapp_package_version.$action,
+            });
+        }
+
+	return webapp_package_version;
     },
     async get_webapp_package_version_entry ( input ) {
 	const result			= await this.call( new ActionHash( input ) );
@@ -387,8 +589,22 @@ export const AppHubCSRZomelet		= new Zomelet({
     },
     async get_webapp_package_version ( input ) {
 	const result			= await this.call( new ActionHash( input ) );
+	const webapp_version		= new WebAppPackageVersion( result.version, this );
 
-	return new WebAppPackageVersion( result, this );
+	webapp_version.matching_rebuild_attestations	= result.matching_rebuild_attestations;
+
+	return webapp_version;
+    },
+    async diff_webapp_package_versions ( input ) {
+	return await this.call({
+	    "from": new ActionHash( input.from ),
+	    "to": new ActionHash( input.to ),
+	});
+    },
+    async get_compatible_app_versions ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return result.map( entry => new WebAppPackageVersion( entry, this ) );
     },
     async move_webapp_package_version ( input ) {
 	const result			= await this.call( input );
@@ -399,11 +615,77 @@ export const AppHubCSRZomelet		= new Zomelet({
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
 
+    //
+    // Review
+    //
+    async create_review ( input ) {
+	const result			= await this.call({
+	    "for_version":	new ActionHash( input.for_version ),
+	    "verdict":		input.verdict,
+	    "message":		input.message,
+	    "security_score":	input.security_score ?? null,
+	});
+
+	return new Review( result, this );
+    },
+    async get_review ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new Review( result, this );
+    },
+    async get_reviews_for_version ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return result.map( entry => new Review( entry, this ) );
+    },
+    async get_reviews_for_agent ( input ) {
+	const result			= await this.call( input ? new AgentPubKey( input ) : null );
+
+	return result.map( entry => new Review( entry, this ) );
+    },
+    async get_review_summary ( input ) {
+	return await this.call( new ActionHash( input ) );
+    },
+    async update_review ( input ) {
+	const result			= await this.call( input );
+
+	return new Review( result, this );
+    },
+    async delete_review ( input ) {
+	return new ActionHash( await this.call( new ActionHash( input ) ) );
+    },
+
+    //
+    // Rebuild Attestation
+    //
+    async submit_rebuild_attestation ( input ) {
+	const result			= await this.call({
+	    "for_version":	new ActionHash( input.for_version ),
+	    "hash":		input.hash,
+	    "build_provenance":	input.build_provenance ?? null,
+	});
+
+	return new RebuildAttestation( result, this );
+    },
+    async get_rebuild_attestation ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new RebuildAttestation( result, this );
+    },
+    async get_rebuild_attestations_for_version ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return result.map( entry => new RebuildAttestation( entry, this ) );
+    },
+    async delete_rebuild_attestation ( input ) {
+	return new ActionHash( await this.call( new ActionHash( input ) ) );
+    },
+
 
     //
     // Virtual functions
     //
-    async save_app ( bytes ) {
+    async save_app ( bytes, options = {} ) {
 	const claimed_file_size		= bytes.length;
 	const bundle			= new Bundle( bytes, "happ" );
 	const roles_dna_tokens		= {};
@@ -434,8 +716,14 @@ export const AppHubCSRZomelet		= new Zomelet({
 	    "resources": bundle.resources,
 	    roles_dna_tokens,
 	    claimed_file_size,
+	    "strict": options.strict === true,
 	});
     },
+    async save_bundle ( bytes ) {
+	const addr			= await this.zomes.mere_memory_api.save( bytes );
+
+	return await this.functions.ingest_bundle( addr );
+    },
     async save_ui ( bytes ) {
 	const addr			= await this.zomes.mere_memory_api.save( bytes );
 
@@ -443,7 +731,7 @@ export const AppHubCSRZomelet		= new Zomelet({
 	    "mere_memory_addr": addr,
 	});
     },
-    async save_webapp ( bytes ) {
+    async save_webapp ( bytes, options = {} ) {
 	const bundle			= new Bundle( bytes, "webhapp" );
 
 	{
@@ -452,7 +740,7 @@ export const AppHubCSRZomelet		= new Zomelet({
 	    const happ_bytes		= bundle.resources[ rpath ];
 	    this.log.debug("Save hApp resource '%s' (%s bytes)", rpath, happ_bytes.length );
 
-	    let app			= await this.functions.save_app( happ_bytes );
+	    let app			= await this.functions.save_app( happ_bytes, options );
 
 	    bundle.resources[ rpath ]	= app.$addr;;
 	}
@@ -470,6 +758,7 @@ export const AppHubCSRZomelet		= new Zomelet({
 	return await this.functions.create_webapp({
 	    "manifest": bundle.manifest,
 	    "resources": bundle.resources,
+	    "strict": options.strict === true,
 	});
     },
     async get_webapp_package_versions_sorted ( input ) {
@@ -502,59 +791,15 @@ export const AppHubCSRZomelet		= new Zomelet({
     },
     // "get_app_bundle":			"get_happ_bundle",
     async get_happ_bundle ( input ) {
-	const app_entry			= await this.functions.get_app_entry( input );
-	const manifest			= app_entry.manifest;
-	const resources			= {};
-
-	this.log.info("Fetch assets for App manifest:", manifest );
-	for ( let role_manifest of manifest.roles ) {
-	    const rpath			= role_manifest.dna.bundled;
-	    const dna_hrl		= app_entry.resources[ rpath ];
-	    const dnahub		= this.getCellInterface( "dnahub", dna_hrl.dna );
-
-	    resources[ rpath ]		= await dnahub.dnahub_csr.get_dna_bundle( dna_hrl.target );
-	}
-
-	const bundle			= new Bundle({
-	    "manifest":		{
-		"manifest_version": "1",
-		...manifest,
-	    },
-	    resources,
-	}, "happ");
+	const result			= await this.call( new EntryHash( input ) );
 
-	return bundle.toBytes({ sortKeys: true });
+	return new Uint8Array( result );
     },
     // "get_webapp_bundle":		"get_webhapp_bundle",
     async get_webhapp_bundle ( input ) {
-	const webapp_entry		= await this.functions.get_webapp_entry( input );
-	const manifest			= webapp_entry.manifest;
-	const resources			= {};
-	this.log.info("Fetch assets for WebApp manifest:", manifest );
-
-	{
-	    const rpath			= manifest.ui.bundled;
-	    resources[ rpath ]		= await this.functions.get_ui_entry_memory(
-		webapp_entry.resources[ rpath ]
-	    );
-	}
-
-	{
-	    const rpath			= manifest.happ_manifest.bundled;
-	    resources[ rpath ]		= await this.functions.get_happ_bundle(
-		webapp_entry.resources[ rpath ]
-	    );
-	}
-
-	const bundle			= new Bundle({
-	    "manifest":		{
-		"manifest_version": "1",
-		...manifest,
-	    },
-	    resources,
-	}, "webhapp");
+	const result			= await this.call( new EntryHash( input ) );
 
-	return bundle.toBytes({ sortKeys: true });
+	return new Uint8Array( result );
     },
     async bundle_from_app_asset ( app_asset ) {
 	const manifest			= app_asset.app_entry.manifest;
@@ -604,6 +849,7 @@ export const AppHubCSRZomelet		= new Zomelet({
 }, {
     "zomes": {
 	"mere_memory_api": MereMemoryZomelet,
+        "coop_content_csr": CoopContentZomelet,
     },
     "cells": {
 	"dnahub": DnaHubCell,
@@ -619,6 +865,7 @@ export const AppHubCSRZomelet		= new Zomelet({
 export const AppHubCell			= new CellZomelets({
     "apphub_csr": AppHubCSRZomelet,
     "mere_memory_api": MereMemoryZomelet,
+    "coop_content_csr": CoopContentZomelet,
 });
 
 export  {
@@ -141,7 +141,14 @@ export class WebApp extends ScopedEntity {
 //
 // WebAppPackageEntry Handling
 //
-export const MaintainerType	= String;
+export function Authority ( data ) {
+    if ( data.type === "agent" )
+        data.content            = new AgentPubKey( data.content );
+    else if ( data.type === "group" )
+        data.content            = intoStruct( data.content, [ ActionHash, ActionHash ] );
+
+    return data;
+}
 
 export const WebAppPackageStruct = {
     "title":			String,
@@ -149,16 +156,12 @@ export const WebAppPackageStruct = {
     "description":		String,
     "icon":			EntryHash,
     "source_code_uri":		OptionType( String ),
-    "maintainer": {
-	"type":			MaintainerType,
-	"content":		AgentPubKey, // [ ActionHash, ActionHash ]
-    },
+    "maintainer":               Authority,
     "deprecation":		OptionType( Object ),
     "metadata":			Object,
 };
 
 export function WebAppPackageEntry ( entry ) {
-    // entry.maintainer.type	= Object.keys( entry.maintainer.type )[0];
     return intoStruct( entry, WebAppPackageStruct );
 }
 
@@ -199,20 +202,29 @@ export class WebAppPackage extends ScopedEntity {
 //
 // WebAppPackageVersionEntry Handling
 //
+export const BuildProvenanceStruct = {
+    "repository_url":		String,
+    "commit_hash":		String,
+    "toolchain_versions":	MapType( String, String ),
+    "build_command":		String,
+    "flake_lock_hash":		OptionType( String ),
+};
+
 export const WebAppPackageVersionStruct = {
     // The version value comes from the link tag (not the entry) so it will only be present when
     // fetched in the context of a 'get_links'
     "version":			OptionType( String ),
+    // The attestation count is calculated by the version getter so it will only be present when
+    // fetched using 'get_*_package_version'
+    "matching_rebuild_attestations":	OptionType( Number ),
 
     "for_package":		ActionHash,
     "changelog":		OptionType( String ),
     "webapp":			EntryHash,
     "webapp_token":		WebAppTokenStruct,
     "source_code_revision_uri":	OptionType( String ),
-    "maintainer": {
-	"type":			MaintainerType,
-	"content":		AgentPubKey, // [ ActionHash, ActionHash ]
-    },
+    "build_provenance":		OptionType( BuildProvenanceStruct ),
+    "maintainer":               Authority,
     "metadata":			Object,
 };
 
@@ -273,6 +285,45 @@ export function WebAppAsset ( entry ) {
 
 
 
+//
+// ReviewEntry Handling
+//
+export const ReviewStruct = {
+    "reviewer":			AgentPubKey,
+    "for_version":		ActionHash,
+    "verdict":			String,
+    "message":			String,
+    "security_score":		OptionType( Number ),
+};
+
+export function ReviewEntry ( entry ) {
+    return intoStruct( entry, ReviewStruct );
+}
+
+export class Review extends ScopedEntity {
+    static STRUCT		= ReviewStruct;
+}
+
+
+//
+// RebuildAttestationEntry Handling
+//
+export const RebuildAttestationStruct = {
+    "attester":			AgentPubKey,
+    "for_version":		ActionHash,
+    "hash":			String,
+    "build_provenance":		OptionType( BuildProvenanceStruct ),
+};
+
+export function RebuildAttestationEntry ( entry ) {
+    return intoStruct( entry, RebuildAttestationStruct );
+}
+
+export class RebuildAttestation extends ScopedEntity {
+    static STRUCT		= RebuildAttestationStruct;
+}
+
+
 export default {
     LinkStruct,
     Link,
@@ -299,4 +350,13 @@ export default {
 
     WebAppAssetStruct,
     WebAppAsset,
+
+    ReviewStruct,
+    ReviewEntry,
+    Review,
+
+    BuildProvenanceStruct,
+    RebuildAttestationStruct,
+    RebuildAttestationEntry,
+    RebuildAttestation,
 };
@@ -7,25 +7,56 @@ pub use devhub_sdk::*;
 use std::collections::BTreeMap;
 use hdk::prelude::*;
 use hdk_extensions::{
+    agent_id,
     must_get,
     hdi_extensions::{
         guest_error,
     },
 };
 use serde_bytes::*;
+use holo_hash::{
+    WasmHash,
+    WasmHashB64,
+};
 use zomehub_sdk::{
     ZomeAsset,
+    CreateZomeEntryInput,
+    IngestedZome,
+    ZOMEHUB_ROLE_NAME,
+    save_zomehub_memory,
+    zomehub_types::{
+        ZomeEntry,
+        ZomeType,
+        mere_memory_types::MemoryEntry,
+    },
 };
 use dnahub_types::{
     RmpvValue,
+    Authority,
     DnaEntry,
+    DnaPackageEntry,
+    DnaPackageVersionEntry,
     DnaToken,
     DnaManifestV1,
     DnaAssetHashes,
     ResourcesMap,
+    HRL,
+    hash,
+};
+use hc_crud::{
+    Entity, EntityId,
 };
 
 
+/// Role name of the DnaHub DNA in the DevHub hApp
+pub const DNAHUB_ROLE_NAME : &str = "dnahub";
+
+pub type EntityMap<T> = BTreeMap<String, Entity<T>>;
+pub type EntityPointerMap = BTreeMap<String, EntityId>;
+
+pub type DnaPackageMap = EntityMap<DnaPackageEntry>;
+pub type DnaPackageVersionMap = EntityMap<DnaPackageVersionEntry>;
+
 pub type IntegritiesTokenInput = Vec<(String, ByteBuf)>;
 pub type CoordinatorsTokenInput = Vec<(String, ByteBuf)>;
 
@@ -84,6 +115,9 @@ pub struct CreateDnaInput {
     pub resources: ResourcesMap,
     pub claimed_file_size: u64,
     pub asset_hashes: DnaAssetHashes,
+    /// Reject the DNA unless every zome passes [`verify_dna_entry`]
+    #[serde(default)]
+    pub strict: bool,
 }
 
 impl CreateDnaInput {
@@ -117,6 +151,86 @@ impl TryFrom<CreateDnaInput> for DnaEntry {
 }
 
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreateDnaPackageInput {
+    pub name: String,
+    pub title: String,
+    pub description: String,
+
+    // optional
+    pub maintainer: Option<Authority>,
+    pub tags: Option<Vec<String>>,
+
+    // Common fields
+    #[serde(default)]
+    pub metadata: BTreeMap<String, RmpvValue>,
+}
+
+impl TryFrom<CreateDnaPackageInput> for DnaPackageEntry {
+    type Error = WasmError;
+
+    fn try_from(input: CreateDnaPackageInput) -> ExternResult<Self> {
+        Ok(
+            Self {
+                name: input.name,
+                title: input.title,
+                description: input.description,
+                maintainer: input.maintainer
+                    .unwrap_or( agent_id()?.into() ),
+                metadata: input.metadata,
+
+                // optional
+                tags: input.tags,
+            }
+        )
+    }
+}
+
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreateDnaPackageVersionInput {
+    pub for_package: EntityId,
+    pub version: String,
+    pub dna_entry: EntryHash,
+
+    // optional
+    pub readme: Option<EntryHash>,
+    pub maintainer: Option<Authority>,
+    pub changelog: Option<EntryHash>,
+    pub source_code_revision_uri: Option<String>,
+
+    // Common fields
+    #[serde(default)]
+    pub metadata: BTreeMap<String, RmpvValue>,
+}
+
+impl TryFrom<CreateDnaPackageVersionInput> for DnaPackageVersionEntry {
+    type Error = WasmError;
+
+    fn try_from(input: CreateDnaPackageVersionInput) -> ExternResult<Self> {
+        Ok(
+            Self {
+                for_package: input.for_package.clone(),
+                dna_entry: input.dna_entry,
+                maintainer: match input.maintainer {
+                    None => {
+                        let dna_package : DnaPackageEntry = must_get( &input.for_package )?.try_into()?;
+
+                        dna_package.maintainer
+                    },
+                    Some(auth) => auth,
+                },
+
+                readme: input.readme,
+                changelog: input.changelog,
+                source_code_revision_uri: input.source_code_revision_uri,
+                metadata: input.metadata,
+            }
+        )
+    }
+}
+
+
 pub type ZomeAssetMap = BTreeMap<ZomeName, ZomeAsset>;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -173,3 +287,448 @@ impl TryInto<DnaAsset> for EntryHash {
         )
     }
 }
+
+impl DnaAsset {
+    /// Assemble the installable `.dna` bundle bytes
+    pub fn to_bundle_bytes(&self) -> ExternResult<Vec<u8>> {
+        let manifest = self.dna_entry.deserialized_manifest()?;
+        let zome_paths = manifest.integrity.zomes.iter()
+            .map( |zome_manifest| ( &zome_manifest.name, &zome_manifest.bundled ) )
+            .chain(
+                manifest.coordinator.zomes.iter()
+                    .map( |zome_manifest| ( &zome_manifest.name, &zome_manifest.bundled ) )
+            );
+        let mut resources = BundleResources::new();
+
+        for (name, rpath) in zome_paths {
+            let zome_asset = self.zome_assets.get( name )
+                .ok_or(guest_error!(format!(
+                    "DnaAsset is missing zome asset for '{}'", name,
+                )))?;
+
+            resources.insert(
+                rpath.to_owned(),
+                decompress_memory_bytes(
+                    &zome_asset.memory_entry.compression,
+                    zome_asset.bytes.clone(),
+                )?,
+            );
+        }
+
+        encode_bundle( &self.dna_entry.manifest, resources )
+    }
+}
+
+
+/// Verification result for a single zome in a DNA manifest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ZomeVerification {
+    pub name: String,
+    /// The placement of the zome in the manifest
+    pub zome_type: ZomeType,
+    pub resource: Option<HRL>,
+    pub zome_entry: Option<ZomeEntry>,
+    pub errors: Vec<String>,
+}
+
+impl ZomeVerification {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DnaVerificationReport {
+    /// Errors that are not specific to a zome (eg. the DNA token does not match the manifest)
+    pub errors: Vec<String>,
+    pub zomes: Vec<ZomeVerification>,
+    pub valid: bool,
+}
+
+
+/// A zome's memory entry and its (possibly compressed) bytes
+type MemoryBytes = (MemoryEntry, Vec<u8>);
+
+fn get_resource_zome(hrl: &HRL, with_bytes: bool) ->
+    ExternResult<(ZomeEntry, Option<MemoryBytes>)>
+{
+    if !with_bytes {
+        let entity : Entity<ZomeEntry> = call_cell(
+            hrl.dna.clone(),
+            "zomehub_csr",
+            "get_zome_entry",
+            hrl.target.clone(),
+            (),
+        )?;
+
+        return Ok(( entity.content, None ));
+    }
+
+    let zome_asset : ZomeAsset = call_cell(
+        hrl.dna.clone(),
+        "zomehub_csr",
+        "get_zome_asset",
+        hrl.target.clone(),
+        (),
+    )?;
+
+    Ok(( zome_asset.zome_entry, Some(( zome_asset.memory_entry, zome_asset.bytes )) ))
+}
+
+
+fn verify_dna_zome(
+    dna_entry: &DnaEntry,
+    name: &str,
+    bundled: &str,
+    zome_type: ZomeType,
+    manifest_hash: &Option<WasmHashB64>,
+) -> ExternResult<ZomeVerification> {
+    let mut report = ZomeVerification {
+        name: name.to_string(),
+        zome_type: zome_type.clone(),
+        resource: dna_entry.resources.get( bundled ).cloned(),
+        zome_entry: None,
+        errors: vec![],
+    };
+
+    let hrl = match &report.resource {
+        Some(hrl) => hrl.clone(),
+        None => {
+            report.errors.push(format!(
+                "DnaEntry does not have resources with path '{}'", bundled,
+            ));
+            return Ok( report );
+        },
+    };
+
+    let (zome_entry, maybe_memory) = match get_resource_zome( &hrl, manifest_hash.is_some() ) {
+        Ok(result) => result,
+        Err(err) => {
+            report.errors.push(format!(
+                "Failed to get zome for resource '{}' ({}): {:?}", bundled, hrl.target, err,
+            ));
+            return Ok( report );
+        },
+    };
+
+    if zome_entry.zome_type != zome_type {
+        report.errors.push(format!(
+            "Zome type '{}' does not match manifest placement '{}'",
+            String::from( zome_entry.zome_type.clone() ), String::from( zome_type.clone() ),
+        ));
+    }
+
+    let asset_hashes = match zome_type {
+        ZomeType::Integrity => &dna_entry.asset_hashes.integrity,
+        ZomeType::Coordinator => &dna_entry.asset_hashes.coordinator,
+    };
+
+    match asset_hashes.get( name ) {
+        Some(asset_hash) if *asset_hash != zome_entry.hash => report.errors.push(format!(
+            "Asset hash '{}' does not match zome hash '{}'", asset_hash, zome_entry.hash,
+        )),
+        Some(_) => (),
+        None => report.errors.push(format!(
+            "Missing asset hash for '{}'", name,
+        )),
+    }
+
+    if let (Some(expected_hash), Some((memory_entry, bytes))) = (manifest_hash, maybe_memory) {
+        // The manifest hash is of the WASM itself so compressed memory must be expanded first
+        match decompress_memory_bytes( &memory_entry.compression, bytes ) {
+            Ok(wasm) => {
+                let digest = hash_blake2b( wasm, 32 )?;
+                let expected_digest = WasmHash::from( expected_hash.clone() );
+
+                if digest != expected_digest.get_raw_32() {
+                    report.errors.push(format!(
+                        "Manifest hash '{}' does not match the blake2b hash of the WASM bytes", expected_hash,
+                    ));
+                }
+            },
+            Err(err) => report.errors.push(format!(
+                "Could not verify manifest hash '{}' because the WASM bytes could not be decompressed: {:?}",
+                expected_hash, err,
+            )),
+        }
+    }
+
+    report.zome_entry = Some( zome_entry );
+
+    Ok( report )
+}
+
+
+/// Check that each zome in the manifest is backed by a real `ZomeEntry` in zomehub
+///
+/// For every zome this compares the `ZomeEntry.hash` against `asset_hashes`, the `zome_type`
+/// against its placement in the manifest, and the manifest `hash` against the (decompressed) WASM
+/// bytes.  A zome whose bytes cannot be decompressed is reported with an error instead of being
+/// treated as verified.
+pub fn verify_dna_entry(dna_entry: &DnaEntry) -> ExternResult<DnaVerificationReport> {
+    let manifest = dna_entry.deserialized_manifest()?;
+    let mut errors = vec![];
+    let mut zomes = vec![];
+
+    match manifest.dna_token( &dna_entry.asset_hashes ) {
+        Ok(dna_token) if dna_token != dna_entry.dna_token => errors.push(
+            "DNA token does not match the manifest and asset hashes".to_string()
+        ),
+        Ok(_) => (),
+        Err(err) => errors.push(format!("{:?}", err )),
+    }
+
+    for zome_manifest in manifest.integrity.zomes.iter() {
+        zomes.push( verify_dna_zome(
+            dna_entry,
+            &zome_manifest.name.0,
+            &zome_manifest.bundled,
+            ZomeType::Integrity,
+            &zome_manifest.hash,
+        )? );
+    }
+
+    for zome_manifest in manifest.coordinator.zomes.iter() {
+        zomes.push( verify_dna_zome(
+            dna_entry,
+            &zome_manifest.name.0,
+            &zome_manifest.bundled,
+            ZomeType::Coordinator,
+            &zome_manifest.hash,
+        )? );
+    }
+
+    let valid = errors.is_empty() && zomes.iter().all( |zome| zome.is_valid() );
+
+    Ok(
+        DnaVerificationReport {
+            errors,
+            zomes,
+            valid,
+        }
+    )
+}
+
+
+/// The entities created (or reused) when ingesting a `.dna` bundle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestedDna {
+    /// The DnaHub DNA hash so that callers in other cells can build an HRL
+    pub dna: DnaHash,
+    pub dna_entry: Entity<DnaEntry>,
+    /// Zome entities keyed by zome name
+    pub zomes: BTreeMap<String, Entity<ZomeEntry>>,
+}
+
+
+/// Unpack `.dna` bundle bytes, ingest each zome into ZomeHub and create the `DnaEntry`
+///
+/// This must run in the DnaHub cell; the zomes are saved to mere_memory and created in the
+/// `zomehub` role.  `claimed_file_size` is the size of the `.dna` file that `bytes` came from.
+pub fn ingest_dna_bundle(bytes: &[u8], claimed_file_size: u64) -> ExternResult<IngestedDna> {
+    let (manifest, resources) = decode_bundle( bytes )?;
+    let dna_manifest = DnaEntry::deserialize_manifest( &manifest )?;
+    let zome_manifests = dna_manifest.integrity.zomes.iter()
+        .map( |zome_manifest| ( ZomeType::Integrity, &zome_manifest.name, &zome_manifest.bundled ) )
+        .chain(
+            dna_manifest.coordinator.zomes.iter()
+                .map( |zome_manifest| ( ZomeType::Coordinator, &zome_manifest.name, &zome_manifest.bundled ) )
+        );
+
+    let mut hrls = ResourcesMap::new();
+    let mut zomes = BTreeMap::new();
+    let mut asset_hashes = DnaAssetHashes {
+        integrity: BTreeMap::new(),
+        coordinator: BTreeMap::new(),
+    };
+
+    for (zome_type, name, rpath) in zome_manifests {
+        let zome_bytes = resources.get( rpath )
+            .ok_or(guest_error!(format!(
+                "DNA bundle does not have resource for path '{}'", rpath,
+            )))?;
+        let ingested : IngestedZome = call_role(
+            ZOMEHUB_ROLE_NAME,
+            "zomehub_csr",
+            "ingest_zome",
+            CreateZomeEntryInput {
+                zome_type: zome_type.clone(),
+                mere_memory_addr: save_zomehub_memory( zome_bytes )?,
+            },
+            (),
+        )?;
+
+        match zome_type {
+            ZomeType::Integrity => &mut asset_hashes.integrity,
+            ZomeType::Coordinator => &mut asset_hashes.coordinator,
+        }.insert( name.to_string(), ingested.zome.content.hash.clone() );
+
+        hrls.insert( rpath.to_owned(), HRL {
+            dna: ingested.dna,
+            target: ingested.zome.address.clone().into(),
+        });
+        zomes.insert( name.to_string(), ingested.zome );
+    }
+
+    let dna_entry : Entity<DnaEntry> = call_zome(
+        "dnahub_csr",
+        "create_dna",
+        CreateDnaInput {
+            manifest,
+            resources: hrls,
+            claimed_file_size,
+            asset_hashes,
+            strict: false,
+        },
+        (),
+    )?;
+
+    Ok(
+        IngestedDna {
+            dna: dna_info()?.hash,
+            dna_entry,
+            zomes,
+        }
+    )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DiffDnaEntriesInput {
+    pub from: EntryHash,
+    pub to: EntryHash,
+}
+
+
+/// Zome names grouped by how they differ between two DNA entries
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ZomesDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl ZomesDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DnaEntryDiff {
+    /// Any change here is network-breaking
+    pub integrity_zomes: ZomesDiff,
+    /// Changes here can be hot-swapped on an existing network
+    pub coordinator_zomes: ZomesDiff,
+    pub properties_changed: bool,
+    pub network_seed_changed: bool,
+    pub origin_time_changed: bool,
+    /// True when both entries have the same `DnaToken.integrity_hash`
+    pub network_compatible: bool,
+    /// True when no migration is needed (ie. any differences are limited to coordinators)
+    pub coordinator_update: bool,
+}
+
+
+type ZomeFingerprints = BTreeMap<String, (Vec<u8>, Option<AnyDhtHash>)>;
+/// Either an `IntegritiesToken` or a `CoordinatorsToken`
+type ZomesToken = Vec<(String, Vec<u8>)>;
+
+/// Map each zome name to its token and (when present) the address of its resource
+///
+/// The resource address is included because a coordinator token is derived from the manifest
+/// only, so a changed wasm with the same manifest would otherwise go unnoticed.
+fn zome_fingerprints(dna_entry: &DnaEntry, zome_type: ZomeType) -> ExternResult<ZomeFingerprints> {
+    let manifest = dna_entry.deserialized_manifest()?;
+    let (token, paths) : (&ZomesToken, BTreeMap<String, String>) = match zome_type {
+        ZomeType::Integrity => (
+            &dna_entry.integrities_token,
+            manifest.integrity.zomes.into_iter()
+                .map( |zome_manifest| ( zome_manifest.name.0.to_string(), zome_manifest.bundled ) )
+                .collect(),
+        ),
+        ZomeType::Coordinator => (
+            &dna_entry.coordinators_token,
+            manifest.coordinator.zomes.into_iter()
+                .map( |zome_manifest| ( zome_manifest.name.0.to_string(), zome_manifest.bundled ) )
+                .collect(),
+        ),
+    };
+
+    Ok(
+        token.iter()
+            .map( |(name, zome_token)| {
+                let target = paths.get( name )
+                    .and_then( |rpath| dna_entry.resources.get( rpath ) )
+                    .map( |hrl| hrl.target.clone() );
+
+                ( name.to_owned(), ( zome_token.to_owned(), target ) )
+            })
+            .collect()
+    )
+}
+
+
+fn diff_zomes(from: &ZomeFingerprints, to: &ZomeFingerprints) -> ZomesDiff {
+    let mut diff = ZomesDiff::default();
+
+    for (name, fingerprint) in to.iter() {
+        match from.get( name ) {
+            None => diff.added.push( name.to_owned() ),
+            Some(prev) if prev != fingerprint => diff.changed.push( name.to_owned() ),
+            Some(_) => (),
+        }
+    }
+
+    for name in from.keys() {
+        if !to.contains_key( name ) {
+            diff.removed.push( name.to_owned() );
+        }
+    }
+
+    diff
+}
+
+
+/// Compare two DNA entries zome by zome to decide between a coordinator update and a migration
+pub fn diff_dna_entries(from: &DnaEntry, to: &DnaEntry) -> ExternResult<DnaEntryDiff> {
+    let from_manifest = from.deserialized_manifest()?;
+    let to_manifest = to.deserialized_manifest()?;
+
+    let integrity_zomes = diff_zomes(
+        &zome_fingerprints( from, ZomeType::Integrity )?,
+        &zome_fingerprints( to, ZomeType::Integrity )?,
+    );
+    let coordinator_zomes = diff_zomes(
+        &zome_fingerprints( from, ZomeType::Coordinator )?,
+        &zome_fingerprints( to, ZomeType::Coordinator )?,
+    );
+
+    let properties_changed = hash( &from_manifest.integrity.properties )?
+        != hash( &to_manifest.integrity.properties )?;
+    let network_seed_changed = from_manifest.integrity.network_seed
+        != to_manifest.integrity.network_seed;
+    let origin_time_changed = hash( &from_manifest.integrity.origin_time )?
+        != hash( &to_manifest.integrity.origin_time )?;
+    let network_compatible = from.dna_token.integrity_hash == to.dna_token.integrity_hash;
+
+    let coordinator_update = network_compatible
+        && integrity_zomes.is_empty()
+        && !properties_changed
+        && !network_seed_changed
+        && !origin_time_changed;
+
+    Ok(
+        DnaEntryDiff {
+            integrity_zomes,
+            coordinator_zomes,
+            properties_changed,
+            network_seed_changed,
+            origin_time_changed,
+            network_compatible,
+            coordinator_update,
+        }
+    )
+}
@@ -43,6 +43,8 @@ pub struct DnaEntry {
     /// Map of coordinator zomes and and their tokens (hash identifier)
     pub coordinators_token: CoordinatorsToken,
     /// DNA bundle size declared by creator
+    ///
+    /// Not verified; storage quotas are charged on [`DnaEntry::entry_size`] instead
     pub claimed_file_size: u64,
     /// Map of zome (WASM) assets and their content hashes
     pub asset_hashes: DnaAssetHashes,
@@ -67,6 +69,17 @@ impl DnaEntry {
         DnaEntry::deserialize_manifest( &self.manifest )
     }
 
+    /// Size of the serialized entry, which is what this DNA stores for it (the zome WASM is
+    /// stored and counted in ZomeHub)
+    pub fn entry_size(&self) -> ExternResult<u64> {
+        let bytes = encode( self )
+            .map_err(|e| guest_error!(format!(
+                "Failed to encode DNA entry: {:?}", e
+            )))?;
+
+        Ok( bytes.len() as u64 )
+    }
+
     pub fn integrity_hash(&self) -> Vec<u8> {
         self.dna_token.integrity_hash.clone()
     }
@@ -0,0 +1,34 @@
+use crate::{
+    hdi,
+    Authority,
+    devhub_types::Maintained,
+    RmpvValue,
+};
+use std::collections::BTreeMap;
+use hdi::prelude::*;
+
+
+
+//
+// DNA Package Entry
+//
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct DnaPackageEntry {
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    pub maintainer: Authority,
+
+    // optional
+    pub tags: Option<Vec<String>>,
+
+    // Common fields
+    pub metadata: BTreeMap<String, RmpvValue>,
+}
+
+impl Maintained for DnaPackageEntry {
+    fn maintainer(&self) -> &Authority {
+        &self.maintainer
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    hdi,
+    EntityId,
+    Authority,
+    RmpvValue,
+};
+
+use std::collections::BTreeMap;
+use hdi::prelude::*;
+
+
+//
+// DNA Package Version Entry
+//
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct DnaPackageVersionEntry {
+    /// A pointer to the Package ID that this version is related to.
+    ///
+    /// Create
+    ///   - Any [`ActionHash`] that is a Create for DnaPackageEntry
+    /// Update
+    ///   - Cannot be updated
+    pub for_package: EntityId,
+
+    /// A pointer to the DNA for this version.
+    ///
+    /// Create
+    ///   - Any [`EntryHash`] that is a Create for DnaEntry
+    /// Update
+    ///   - Cannot be updated
+    pub dna_entry: EntryHash,
+
+    /// Declares the [Authority] with update permissions for this entry.
+    ///
+    /// Create / Update
+    ///   - Must match the `for_package` DnaPackageEntry's maintainer setting but the group
+    ///     revision pointer should be the latest known group revision.
+    pub maintainer: Authority,
+
+    // Optional
+    pub readme: Option<EntryHash>, // Mere memory addr for README.md
+    pub changelog: Option<EntryHash>,
+    pub source_code_revision_uri: Option<String>,
+
+    // Common fields
+    pub metadata: BTreeMap<String, RmpvValue>,
+}
@@ -1,11 +1,17 @@
 mod holochain_types;
 mod dna_entry;
+mod dna_package_entry;
+mod dna_package_version_entry;
 
 pub use hdi_extensions;
 pub use hdi_extensions::hdi;
 pub use holochain_types::*;
+pub use devhub_types;
+pub use devhub_types::Authority;
 
 pub use dna_entry::*;
+pub use dna_package_entry::*;
+pub use dna_package_version_entry::*;
 
 use std::collections::BTreeMap;
 use rmp_serde;
@@ -22,6 +28,7 @@ pub type CoordinatorsToken = Vec<(String, Vec<u8>)>;
 pub type AssetHashes = BTreeMap<String, String>;
 pub type ResourcesMap = BTreeMap<String, HRL>;
 pub type RmpvValue = rmpv::Value;
+pub type EntityId = ActionHash;
 
 
 
@@ -39,6 +46,31 @@ pub struct DnaToken {
     pub coordinators_token_hash: Vec<u8>,
 }
 
+impl DnaToken {
+    /// Path anchor for finding DNA entries with the same token
+    pub fn anchor_path(&self) -> Path {
+        Path::from( vec![
+            Component::from("dna_token"),
+            Component::from( self.integrity_hash.clone() ),
+            Component::from( self.integrities_token_hash.clone() ),
+            Component::from( self.coordinators_token_hash.clone() ),
+        ])
+    }
+
+    /// Path anchor for finding network compatible DNA entries
+    pub fn integrity_anchor_path(&self) -> Path {
+        integrity_hash_anchor_path( &self.integrity_hash )
+    }
+}
+
+
+pub fn integrity_hash_anchor_path(integrity_hash: &[u8]) -> Path {
+    Path::from( vec![
+        Component::from("integrity_hash"),
+        Component::from( integrity_hash.to_vec() ),
+    ])
+}
+
 
 
 pub fn serialize<T>(target: &T) -> ExternResult<Vec<u8>>
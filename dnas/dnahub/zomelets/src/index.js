@@ -14,6 +14,8 @@ import {
 import {
     ZomeHubCSRZomelet,
     MereMemoryZomelet,
+    CoopContentZomelet,
+    Link,
 
     ZomeHubCell,
 }					from '@holochain/zomehub-zomelets'; // approx. 57kb
@@ -21,6 +23,8 @@ import {
     DnaEntry,
     Dna,
     DnaAsset,
+    DnaPackage,
+    DnaPackageVersion,
 }					from './types.js';
 
 export const DnaHubCSRZomelet		= new Zomelet({
@@ -60,6 +64,16 @@ export const DnaHubCSRZomelet		= new Zomelet({
 
 	return new Dna( result, this );
     },
+    async get_dna_entries_by_token ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( entry => new Dna( entry, this ) );
+    },
+    async get_dnas_with_integrity_hash ( input ) {
+	const result			= await this.call( new Uint8Array( input ) );
+
+	return result.map( entry => new Dna( entry, this ) );
+    },
     async get_dna_asset ( input ) {
 	const result			= await this.call( new EntryHash( input ) );
 	const dna_asset			= DnaAsset( result );
@@ -96,20 +110,289 @@ export const DnaHubCSRZomelet		= new Zomelet({
 
 	return dna_asset;
     },
+    async ingest_dna ( input ) {
+	const result			= await this.call( new EntryHash( input ) );
+
+	result.dna_entry		= new Dna( result.dna_entry, this );
+
+	return result;
+    },
+    async verify_dna ( input ) {
+	return await this.call( new EntryHash( input ) );
+    },
+    async diff_dna_entries ( input ) {
+	return await this.call({
+	    "from": new EntryHash( input.from ),
+	    "to": new EntryHash( input.to ),
+	});
+    },
     async get_dna_entries_for_agent ( input ) {
 	const entries			= await this.call( input ? new AgentPubKey( input ) : input );
 
 	return entries.map( entry => new Dna( entry ) );
     },
+    async get_dependents ( input ) {
+	const entries			= await this.call( new AnyDhtHash( input ) );
+
+	return entries.map( entry => new Dna( entry ) );
+    },
+    async get_dna_entries_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new Dna( entry ) );
+
+	return result;
+    },
+    async get_agent_storage_usage ( input ) {
+	const result			= await this.call( input ? new AgentPubKey( input ) : null );
+
+	result.agent			= new AgentPubKey( result.agent );
+
+	return result;
+    },
     async delete_dna ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
 
 
+    //
+    // DNA package entry
+    //
+    async create_dna_package_entry ( input ) {
+	const result			= await this.call( input );
+
+	return new DnaPackage( result, this );
+    },
+    async create_dna_package ( input ) {
+	const result			= await this.call( input );
+        const dna_package               = new DnaPackage( result, this );
+
+        if ( dna_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_link({
+                "group_id": dna_package.maintainer.content[0],
+                "content_target": dna_package.$id,
+                "content_type": "dna_package",
+            });
+        }
+
+	return dna_package;
+    },
+    async update_dna_package ( input ) {
+        if ( input.properties.maintainer === undefined ) {
+            const prev_dna_pack         = await this.functions.get_dna_package_entry( input.base );
+            input.properties.maintainer = prev_dna_pack.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const dna_package               = new DnaPackage( result, this );
+
+        if ( dna_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": dna_package.maintainer.content[0],
+                "content_id": dna_package.$id,
+                "content_prev": input.base,
+                "content_next": dna_package.$action,
+            });
+        }
+
+	return dna_package;
+    },
+    async get_dna_package ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new DnaPackage( result, this );
+    },
+    async get_dna_package_by_name ( input ) {
+	const result			= await this.call( input );
+
+	return new DnaPackage( result, this );
+    },
+    async search_dna_packages_by_name_prefix ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entity => new DnaPackage( entity, this ) );
+    },
+    async search_dna_packages_by_name_fuzzy ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entity => new DnaPackage( entity, this ) );
+    },
+    async get_dna_package_entry ( input ) {
+	const result			= await this.call( new AnyDhtHash( input ) );
+
+	return new DnaPackage( result, this );
+    },
+    async get_all_dna_package_links () {
+	const links			= await this.call();
+
+        return links.map( data => new Link(data) );
+    },
+    async get_all_dna_packages_paginated ( input = {} ) {
+	const result			= await this.call( input );
+
+	result.items			= result.items.map( entity => new DnaPackage( entity, this ) );
+
+	return result;
+    },
+    async get_dna_packages_for_agent ( input ) {
+	const entries			= await this.call( input ? new AgentPubKey( input ) : input );
+
+	return Object.fromEntries(
+	    entries.map( entry => {
+		const dna_pack		= new DnaPackage( entry, this );
+		return [
+		    dna_pack.$id,
+		    dna_pack,
+		];
+	    })
+	);
+    },
+    async get_dna_packages_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new DnaPackage( entry, this ) );
+
+	return result;
+    },
+    async get_dna_package_versions ( input ) {
+	const version_map		= await this.call( input );
+
+	for ( let [vtag, pack_version] of Object.entries(version_map) ) {
+	    version_map[ vtag ]		= new DnaPackageVersion( pack_version, this );
+	    version_map[ vtag ].version	= vtag;
+	}
+
+	return version_map;
+    },
+    async get_dna_package_versions_paginated ( input ) {
+	const result			= await this.call({
+	    "input": new ActionHash( input.input ),
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( ([ vtag, pack_version ]) => {
+	    const version		= new DnaPackageVersion( pack_version, this );
+	    version.version		= vtag;
+
+	    return [ vtag, version ];
+	});
+
+	return result;
+    },
+    async delete_dna_package ( input ) {
+	return await this.call( input );
+    },
+
+    // DNA Package Links
+    async create_dna_package_link_to_version ( input ) {
+	return new ActionHash( await this.call( input ) );
+    },
+    async get_dna_package_version_links ( input ) {
+	const links			= await this.call( input );
+
+        return links.map( data => new Link(data) );
+    },
+    async get_dna_package_version_targets ( input ) {
+	const link_map			= await this.call( input );
+
+	for ( let [key, value] of Object.entries( link_map ) ) {
+	    link_map[ key ]		= new ActionHash( value );
+	}
+
+	return link_map;
+    },
+
+
+    //
+    // DNA package version entry
+    //
+    async create_dna_package_version ( input ) {
+        if ( input.maintainer === undefined ) {
+            const dna_package           = await this.functions.get_dna_package( input.for_package );
+            input.maintainer            = dna_package.maintainer;
+
+            if ( input.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.maintainer.content[0] );
+                input.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+
+	result.content.version		= input.version;
+
+        const dna_package_version       = new DnaPackageVersion( result, this );
+
+        if ( dna_package_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_link({
+                "group_id": dna_package_version.maintainer.content[0],
+                "content_target": dna_package_version.$id,
+                "content_type": "dna_package_version",
+            });
+        }
+
+	return dna_package_version;
+    },
+    async create_dna_package_version_entry ( input ) {
+	const result			= await this.call( input );
+
+	return new DnaPackageVersion( result, this );
+    },
+    async get_dna_package_version_entry ( input ) {
+	const result			= await this.call( new AnyDhtHash( input ) );
+
+	return new DnaPackageVersion( result, this );
+    },
+    async get_dna_package_version ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new DnaPackageVersion( result, this );
+    },
+    async update_dna_package_version ( input ) {
+        if ( input.properties.maintainer === undefined ) {
+            const prev_version          = await this.functions.get_dna_package_version_entry( input.base );
+            input.properties.maintainer = prev_version.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const dna_package_version       = new DnaPackageVersion( result, this );
+
+        if ( dna_package_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": dna_package_version.maintainer.content[0],
+                "content_id": dna_package_version.$id,
+                "content_prev": input.base,
+                "content_next": dna_package_version.$action,
+            });
+        }
+
+	return dna_package_version;
+    },
+    async delete_dna_package_version ( input ) {
+	return await this.call( input );
+    },
+
+
     //
     // Virtual functions
     //
-    async save_dna ( bytes ) {
+    async save_dna ( bytes, options = {} ) {
 	const claimed_file_size		= bytes.length;
 	const dna_asset_hashes		= {
 	    "integrity": {},
@@ -154,6 +437,7 @@ export const DnaHubCSRZomelet		= new Zomelet({
 	    "resources": bundle.resources,
 	    claimed_file_size,
 	    "asset_hashes": dna_asset_hashes,
+	    "strict": options.strict === true,
 	});
     },
 
@@ -187,37 +471,9 @@ export const DnaHubCSRZomelet		= new Zomelet({
 	return await zomehub.zomehub_csr.get_zome( zome_hrl.target );
     },
     async get_dna_bundle ( input ) {
-	const dna_entry			= await this.functions.get_dna_entry( input );
-	const resources			= {};
-
-	this.log.normal("Fetch assests for DNA manifest:", dna_entry.manifest );
-	for ( let zome_manifest of dna_entry.manifest.integrity.zomes ) {
-	    const rpath			= zome_manifest.bundled;
-	    const zome_hrl		= dna_entry.resources[ rpath ];
-	    const zomehub		= this.getCellInterface( "zomehub", zome_hrl.dna );
-
-	    const zome			= await zomehub.zomehub_csr.get_zome( zome_hrl.target );
-	    resources[ rpath ]		= zome.bytes;
-	}
-
-	for ( let zome_manifest of dna_entry.manifest.coordinator.zomes ) {
-	    const rpath			= zome_manifest.bundled;
-	    const zome_hrl		= dna_entry.resources[ rpath ];
-	    const zomehub		= this.getCellInterface( "zomehub", zome_hrl.dna );
-
-	    const zome			= await zomehub.zomehub_csr.get_zome( zome_hrl.target );
-	    resources[ rpath ]		= zome.bytes;
-	}
-
-	const bundle			= new Bundle({
-	    "manifest":		{
-		"manifest_version": "1",
-		...dna_entry.manifest,
-	    },
-	    resources,
-	}, "dna");
+	const result			= await this.call( new EntryHash( input ) );
 
-	return bundle.toBytes({ sortKeys: true });
+	return new Uint8Array( result );
     },
     async bundle_from_dna_asset ( dna_asset ) {
 	const manifest			= dna_asset.dna_entry.manifest;
@@ -242,6 +498,9 @@ export const DnaHubCSRZomelet		= new Zomelet({
 	}, "dna");
     },
 }, {
+    "zomes": {
+        "coop_content_csr": CoopContentZomelet,
+    },
     "cells": {
 	"zomehub": ZomeHubCell,
     },
@@ -256,6 +515,7 @@ export const DnaHubCSRZomelet		= new Zomelet({
 
 export const DnaHubCell			= new CellZomelets({
     "dnahub_csr": DnaHubCSRZomelet,
+    "coop_content_csr": CoopContentZomelet,
 });
 
 
@@ -14,6 +14,7 @@ import {
 }					from '@spartan-hc/entities';
 import {
     ZomeAssetStruct,
+    Authority,
 }					from '@holochain/zomehub-zomelets';
 
 
@@ -70,6 +71,62 @@ export function DnaAsset ( entry ) {
 }
 
 
+
+//
+// DnaPackageEntry Handling
+//
+export const DnaPackageStruct = {
+    "name":			String,
+    "title":			String,
+    "description":		String,
+    "maintainer":               Authority,
+    "tags":			OptionType( VecType( String ) ),
+    "metadata":			Object,
+};
+
+export function DnaPackageEntry ( entry ) {
+    return intoStruct( entry, DnaPackageStruct );
+}
+
+export class DnaPackage extends ScopedEntity {
+    static STRUCT		= DnaPackageStruct;
+
+    async $versions () {
+	return await this.zome.get_dna_package_versions( this.$id );
+    }
+}
+
+
+//
+// DnaPackageVersionEntry Handling
+//
+export const DnaPackageVersionStruct = {
+    // The version value comes from the link tag (not the entry) so it will only be present when
+    // fetched in the context of a 'get_links'
+    "version":			OptionType( String ),
+
+    "for_package":		ActionHash,
+    "dna_entry":		EntryHash,
+    "maintainer":               Authority,
+    "readme":		        OptionType( EntryHash ),
+    "changelog":		OptionType( EntryHash ),
+    "source_code_revision_uri":	OptionType( String ),
+    "metadata":			Object,
+};
+
+export function DnaPackageVersionEntry ( entry ) {
+    return intoStruct( entry, DnaPackageVersionStruct );
+}
+
+export class DnaPackageVersion extends ScopedEntity {
+    static STRUCT		= DnaPackageVersionStruct;
+
+    async $getDnaPackage () {
+	return await this.zome.get_dna_package( this.for_package );
+    }
+}
+
+
 export default {
     DnaTokenStruct,
     DnaStruct,
@@ -78,4 +135,12 @@ export default {
 
     DnaAssetStruct,
     DnaAsset,
+
+    DnaPackageStruct,
+    DnaPackageEntry,
+    DnaPackage,
+
+    DnaPackageVersionStruct,
+    DnaPackageVersionEntry,
+    DnaPackageVersion,
 };
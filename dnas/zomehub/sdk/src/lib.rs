@@ -4,10 +4,14 @@ pub use devhub_sdk::*;
 
 use std::collections::BTreeMap;
 use hdk::prelude::*;
+use serde_bytes::ByteBuf;
 use hdk_extensions::{
     agent_id,
     must_get,
 };
+use hdi_extensions::{
+    guest_error,
+};
 use zomehub_types::{
     Authority,
     RmpvValue,
@@ -17,6 +21,10 @@ use zomehub_types::{
     ZomePackageEntry,
     ZomePackageVersionEntry,
     ApiCompatibility,
+    BuildProvenance,
+    ZomeDependency,
+    YankNotice,
+    parse_wasm_exports,
 
     mere_memory_types,
 };
@@ -28,6 +36,9 @@ use hc_crud::{
 };
 
 
+/// Role name of the ZomeHub DNA in the DevHub hApp
+pub const ZOMEHUB_ROLE_NAME : &str = "zomehub";
+
 pub type EntityMap<T> = BTreeMap<String, Entity<T>>;
 pub type EntityPointerMap = BTreeMap<String, EntityId>;
 
@@ -74,6 +85,50 @@ impl TryInto<ZomeAsset> for EntryHash {
 }
 
 
+/// Read a zome's wasm from mere_memory and list its function exports
+///
+/// Returns an error when the bytes are not a wasm module.
+pub fn inspect_wasm_exports(mere_memory_addr: &EntryHash) -> ExternResult<Vec<String>> {
+    let memory_with_bytes : MemoryWithBytes = call_zome(
+        "mere_memory_api",
+        "get_memory_with_bytes",
+        mere_memory_addr.clone(),
+        (),
+    )?;
+    let bytes = decompress_memory_bytes(
+        &memory_with_bytes.0.compression,
+        memory_with_bytes.1,
+    )?;
+
+    parse_wasm_exports( &bytes )
+        .map_err( |err| guest_error!(format!(
+            "Zome memory ({}) is not a valid wasm module: {}", mere_memory_addr, err,
+        )) )
+}
+
+
+/// Identifies a zome package by its ID or by its registered name
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", content = "content")]
+#[serde(rename_all = "snake_case")]
+pub enum ZomePackageRef {
+    Id(EntityId),
+    Name(String),
+}
+
+impl From<EntityId> for ZomePackageRef {
+    fn from(id: EntityId) -> Self {
+        ZomePackageRef::Id(id)
+    }
+}
+
+impl From<String> for ZomePackageRef {
+    fn from(name: String) -> Self {
+        ZomePackageRef::Name(name)
+    }
+}
+
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CreateZomePackageInput {
     pub name: String,
@@ -106,6 +161,9 @@ impl TryFrom<CreateZomePackageInput> for ZomePackageEntry {
 
                 // optional
                 tags: input.tags,
+
+                // state
+                deprecation: None,
             }
         )
     }
@@ -123,8 +181,9 @@ pub struct CreateZomePackageVersionInput {
     pub maintainer: Option<Authority>,
     pub changelog: Option<EntryHash>,
     pub source_code_revision_uri: Option<String>,
+    pub build_provenance: Option<BuildProvenance>,
     pub api_compatibility: ApiCompatibility,
-    pub dependencies: Option<Vec<(String, ActionHash, String, String)>>,
+    pub dependencies: Option<Vec<ZomeDependency>>,
 
     // Common fields
     #[serde(default)]
@@ -151,10 +210,73 @@ impl TryFrom<CreateZomePackageVersionInput> for ZomePackageVersionEntry {
                 readme: input.readme,
                 changelog: input.changelog,
                 source_code_revision_uri: input.source_code_revision_uri,
+                build_provenance: input.build_provenance,
                 api_compatibility: input.api_compatibility,
                 dependencies: input.dependencies,
+                yanked: None,
                 metadata: input.metadata,
             }
         )
     }
 }
+
+
+/// A dependency from a resolved dependency tree; there is at most 1 per package
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResolvedZomeDependency {
+    pub name: String,
+    pub package: EntityId,
+    pub version: String,
+    pub version_id: EntityId,
+    pub zome_type: ZomeType,
+    pub zome_entry: EntryHash,
+    pub hash: String,
+    /// Set when the pinned version has been yanked since it was pinned
+    pub yanked: Option<YankNotice>,
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateZomeEntryInput {
+    pub zome_type: ZomeType,
+    pub mere_memory_addr: EntryHash,
+}
+
+
+/// A zome created (or reused) from WASM bytes
+///
+/// `dna` is the ZomeHub DNA hash so that callers in other cells can build an HRL.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IngestedZome {
+    pub dna: DnaHash,
+    pub zome: Entity<ZomeEntry>,
+}
+
+
+/// Save bytes to the ZomeHub mere_memory from another cell in the DevHub hApp
+pub fn save_zomehub_memory(bytes: &[u8]) -> ExternResult<EntryHash> {
+    call_role(
+        ZOMEHUB_ROLE_NAME,
+        "mere_memory_api",
+        "save_bytes",
+        ByteBuf::from( bytes.to_owned() ),
+        (),
+    )
+}
+
+
+/// Get a memory and its (decompressed) bytes from the ZomeHub mere_memory
+///
+/// Works from any cell in the DevHub hApp, including the ZomeHub cell itself.
+pub fn get_zomehub_memory(addr: &EntryHash) -> ExternResult<(MemoryEntry, Vec<u8>)> {
+    let memory_with_bytes : MemoryWithBytes = call_role(
+        ZOMEHUB_ROLE_NAME,
+        "mere_memory_api",
+        "get_memory_with_bytes",
+        addr.to_owned(),
+        (),
+    )?;
+    let bytes = decompress_memory_bytes( &memory_with_bytes.0.compression, memory_with_bytes.1 )?;
+
+    Ok(( memory_with_bytes.0, bytes ))
+}
@@ -1,14 +1,26 @@
 mod zome_entry;
 mod zome_package_entry;
 mod zome_package_version_entry;
+mod wasm;
 
 pub use hdi_extensions;
 pub use hdi_extensions::hdi;
 pub use mere_memory_types;
+pub use devhub_types;
+pub use devhub_types::Authority;
+pub use devhub_types::{
+    ReviewEntry,
+    ReviewVerdict,
+    ReviewSummary,
+    MAX_SECURITY_SCORE,
+    BuildProvenance,
+    RebuildAttestationEntry,
+};
 
 pub use zome_entry::*;
 pub use zome_package_entry::*;
 pub use zome_package_version_entry::*;
+pub use wasm::*;
 
 use hdi::prelude::*;
 
@@ -19,22 +31,9 @@ pub type RmpvValue = rmpv::Value;
 
 
 
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
-#[serde(tag = "type", content = "content")]
-#[serde(rename_all = "snake_case")]
-pub enum Authority {
-    Group(ActionHash, ActionHash),
-    Agent(AgentPubKey),
-}
-
-impl From<AgentPubKey> for Authority {
-    fn from(agent_pub_key: AgentPubKey) -> Self {
-        Authority::Agent(agent_pub_key)
-    }
-}
-
-impl From<(ActionHash, ActionHash)> for Authority {
-    fn from((group_id, group_addr): (ActionHash, ActionHash)) -> Self {
-        Authority::Group(group_id, group_addr)
-    }
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeprecationNotice {
+    pub message: String,
+    #[serde(default)]
+    pub recommended_alternatives: Vec<ActionHash>,
 }
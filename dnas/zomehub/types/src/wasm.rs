@@ -0,0 +1,219 @@
+use crate::{
+    ZomeType,
+};
+
+
+const WASM_MAGIC : &[u8] = b"\0asm";
+const EXPORT_SECTION_ID : u8 = 7;
+
+/// Callbacks generated for integrity zomes (by `hdi`)
+///
+/// Coordinators that depend on an integrity crate also export these, so they only indicate an
+/// integrity zome when nothing else is exported.
+pub const INTEGRITY_EXPORTS : [&str; 3] = [ "validate", "entry_defs", "genesis_self_check" ];
+
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn read_byte(&mut self) -> Result<u8, String> {
+        let byte = self.bytes.get( self.position )
+            .ok_or( format!("Unexpected end of wasm at byte {}", self.position ) )?;
+        self.position += 1;
+
+        Ok( *byte )
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self.position.checked_add( length )
+            .filter( |end| *end <= self.bytes.len() )
+            .ok_or( format!("Unexpected end of wasm reading {} bytes at byte {}", length, self.position ) )?;
+        let slice = &self.bytes[ self.position..end ];
+        self.position = end;
+
+        Ok( slice )
+    }
+
+    /// Read an unsigned LEB128 encoded u32
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let mut result : u32 = 0;
+
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_byte()?;
+            result |= ( (byte & 0x7f) as u32 ) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok( result );
+            }
+        }
+
+        Err( format!("Invalid LEB128 value at byte {}", self.position ) )
+    }
+
+    fn read_name(&mut self) -> Result<String, String> {
+        let length = self.read_u32()? as usize;
+        let bytes = self.read_bytes( length )?;
+
+        String::from_utf8( bytes.to_vec() )
+            .map_err( |err| format!("Invalid UTF-8 name in wasm: {}", err ) )
+    }
+}
+
+
+/// Get the names of the function exports in a wasm module
+///
+/// Only the module header and the export section are parsed; the other sections are skipped.
+pub fn parse_wasm_exports(bytes: &[u8]) -> Result<Vec<String>, String> {
+    let mut reader = Reader::new( bytes );
+
+    if reader.read_bytes( 4 )? != WASM_MAGIC {
+        return Err( "Not a wasm module; missing magic bytes".to_string() );
+    }
+
+    // Module version
+    reader.read_bytes( 4 )?;
+
+    let mut exports = vec![];
+
+    while !reader.is_empty() {
+        let section_id = reader.read_byte()?;
+        let section_size = reader.read_u32()? as usize;
+        let section = reader.read_bytes( section_size )?;
+
+        if section_id != EXPORT_SECTION_ID {
+            continue;
+        }
+
+        let mut section = Reader::new( section );
+        let count = section.read_u32()?;
+
+        for _ in 0..count {
+            let name = section.read_name()?;
+            let kind = section.read_byte()?;
+            section.read_u32()?; // Export index
+
+            // Only functions can be called by Holochain
+            if kind == 0x00 {
+                exports.push( name );
+            }
+        }
+    }
+
+    Ok( exports )
+}
+
+
+/// Derive the zome type from its exports
+///
+/// Only coordinators can define externs, so any export that is not an integrity callback means
+/// a coordinator.  Returns `None` when neither integrity callbacks nor externs are exported.
+pub fn detect_zome_type(exports: &[String]) -> Option<ZomeType> {
+    // Guest memory helpers (eg. '__hc__allocate_1') and the '__num_entry_types' style counts are
+    // exported by both zome types
+    if exports.iter().any( |name| {
+        !name.starts_with("__") && !INTEGRITY_EXPORTS.contains( &name.as_str() )
+    }) {
+        return Some( ZomeType::Coordinator );
+    }
+
+    if exports.iter().any( |name| INTEGRITY_EXPORTS.contains( &name.as_str() ) ) {
+        return Some( ZomeType::Integrity );
+    }
+
+    None
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A wasm module with a type section (skipped) and an export section
+    fn wasm_with_exports(exports: &[(&str, u8)]) -> Vec<u8> {
+        let mut section = vec![ exports.len() as u8 ];
+
+        for (name, kind) in exports {
+            section.push( name.len() as u8 );
+            section.extend( name.as_bytes() );
+            section.push( *kind );
+            section.push( 0 ); // Export index
+        }
+
+        let mut bytes = WASM_MAGIC.to_vec();
+        bytes.extend( [ 1, 0, 0, 0 ] );
+        bytes.extend( [ 1, 4, 1, 0x60, 0, 0 ] ); // Type section with 1 empty function type
+        bytes.push( EXPORT_SECTION_ID );
+        bytes.push( section.len() as u8 );
+        bytes.extend( section );
+
+        bytes
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map( |name| name.to_string() ).collect()
+    }
+
+    #[test]
+    fn parse_function_exports() {
+        let bytes = wasm_with_exports(&[
+            ( "validate", 0x00 ),
+            ( "memory", 0x02 ),
+            ( "__hc__allocate_1", 0x00 ),
+        ]);
+
+        assert_eq!(
+            parse_wasm_exports( &bytes ).unwrap(),
+            names(&[ "validate", "__hc__allocate_1" ]),
+        );
+    }
+
+    #[test]
+    fn parse_module_without_exports() {
+        let mut bytes = WASM_MAGIC.to_vec();
+        bytes.extend( [ 1, 0, 0, 0 ] );
+
+        assert_eq!( parse_wasm_exports( &bytes ).unwrap(), Vec::<String>::new() );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_wasm() {
+        assert!( parse_wasm_exports( b"not wasm" ).is_err() );
+
+        let bytes = wasm_with_exports(&[ ( "validate", 0x00 ) ]);
+
+        assert!( parse_wasm_exports( &bytes[ ..bytes.len() - 3 ] ).is_err() );
+    }
+
+    #[test]
+    fn detect_integrity() {
+        assert_eq!(
+            detect_zome_type( &names(&[ "validate", "entry_defs", "__num_entry_types" ]) ),
+            Some( ZomeType::Integrity ),
+        );
+    }
+
+    #[test]
+    fn detect_coordinator() {
+        assert_eq!(
+            detect_zome_type( &names(&[ "validate", "create_zome", "__hc__allocate_1" ]) ),
+            Some( ZomeType::Coordinator ),
+        );
+    }
+
+    #[test]
+    fn detect_unknown() {
+        assert_eq!( detect_zome_type( &names(&[ "__hc__allocate_1" ]) ), None );
+        assert_eq!( detect_zome_type( &[] ), None );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::hdi;
+use crate::{
+    hdi,
+    detect_zome_type,
+};
 
 use hdi::prelude::*;
 use hdi_extensions::{
@@ -11,7 +14,7 @@ use serde::{
 };
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ZomeType {
     Integrity,
     Coordinator,
@@ -74,6 +77,20 @@ pub struct ZomeEntry {
     pub mere_memory_addr: EntryHash,
     pub file_size: u64,
     pub hash: String,
+
+    /// Function exports found in the wasm
+    ///
+    /// Always read from the `mere_memory_addr` bytes by the zomehub CSR (see
+    /// `zomehub_sdk::inspect_wasm_exports`) because validation does not reassemble the wasm.
+    #[serde(default)]
+    pub exports: Vec<String>,
+    /// Zome type derived from `exports`
+    ///
+    /// Create
+    ///   - Must be the type that [`detect_zome_type`] derives from `exports`
+    ///   - Must match `zome_type` when a type could be detected
+    #[serde(default)]
+    pub detected_zome_type: Option<ZomeType>,
 }
 
 impl ZomeEntry {
@@ -85,6 +102,8 @@ impl ZomeEntry {
             file_size: memory.uncompressed_size
                 .unwrap_or( memory.memory_size ),
             hash: memory.hash,
+            exports: vec![],
+            detected_zome_type: None,
         };
 
         Ok( entry )
@@ -97,4 +116,22 @@ impl ZomeEntry {
     pub fn new_coordinator( addr: EntryHash ) -> ExternResult<Self> {
         Self::new( ZomeType::Coordinator, addr )
     }
+
+    /// Record the wasm exports and the zome type they indicate
+    pub fn with_exports( mut self, exports: Vec<String> ) -> Self {
+        self.detected_zome_type = detect_zome_type( &exports );
+        self.exports = exports;
+
+        self
+    }
+
+    /// Path anchor for finding zome entries with the same wasm content
+    pub fn hash_anchor_path(&self) -> Path {
+        wasm_hash_anchor_path( &self.hash )
+    }
+}
+
+
+pub fn wasm_hash_anchor_path(hash: &str) -> Path {
+    Path::from( format!("wasm_hash.{}", hash ) )
 }
@@ -2,6 +2,8 @@ use crate::{
     hdi,
     ZomeType,
     Authority,
+    devhub_types::Maintained,
+    DeprecationNotice,
     RmpvValue,
 };
 use std::collections::BTreeMap;
@@ -26,4 +28,25 @@ pub struct ZomePackageEntry {
 
     // Common fields
     pub metadata: BTreeMap<String, RmpvValue>,
+
+    // State
+    #[serde(default)]
+    pub deprecation: Option<DeprecationNotice>,
+}
+
+impl Maintained for ZomePackageEntry {
+    fn maintainer(&self) -> &Authority {
+        &self.maintainer
+    }
+}
+
+
+/// Path anchor `tag.<tag>` for finding zome packages with a (lowercased) tag
+///
+/// Components are built directly so that a '.' in a tag does not add path segments.
+pub fn tag_anchor_path(tag: &str) -> Path {
+    Path::from( vec![
+        Component::new( "tag".as_bytes().to_vec() ),
+        Component::new( tag.as_bytes().to_vec() ),
+    ])
 }
@@ -2,6 +2,7 @@ use crate::{
     hdi,
     EntityId,
     Authority,
+    BuildProvenance,
 };
 
 use std::collections::BTreeMap;
@@ -20,6 +21,76 @@ pub struct ApiCompatibility {
     pub tested_with: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(from = "ZomeDependencyFormat")]
+pub struct ZomeDependency {
+    /// The zome name used in the DNA manifest
+    pub name: String,
+    /// The [`ZomePackageEntry`](crate::ZomePackageEntry) ID
+    pub package: EntityId,
+    /// A semver requirement that the pinned version must satisfy
+    pub version_req: String,
+    /// The pinned [`ZomePackageVersionEntry`] ID
+    ///
+    /// Required on create.  `None` only for legacy dependencies (eg. the `(name, id, version,
+    /// hash)` tuple form) in existing entries, which are resolved to the highest version tag that
+    /// satisfies `version_req`.
+    pub version: Option<EntityId>,
+    /// The `ZomePackageToZomePackageVersion` link that tags the pinned version
+    ///
+    /// Required on create so that the version tag can be checked against `version_req`; filled in
+    /// by `create_zome_package_version_entry` when omitted.
+    pub version_link: Option<ActionHash>,
+    /// Expected hash of the pinned version's WASM (see [`ZomeEntry`](crate::ZomeEntry) `hash`)
+    pub hash: String,
+}
+
+/// Accepts the legacy `(name, id, version, hash)` tuple so that existing entries still deserialize
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ZomeDependencyFormat {
+    Current {
+        name: String,
+        package: EntityId,
+        version_req: String,
+        version: Option<EntityId>,
+        #[serde(default)]
+        version_link: Option<ActionHash>,
+        hash: String,
+    },
+    Legacy( String, EntityId, String, String ),
+}
+
+impl From<ZomeDependencyFormat> for ZomeDependency {
+    fn from(format: ZomeDependencyFormat) -> Self {
+        match format {
+            ZomeDependencyFormat::Current { name, package, version_req, version, version_link, hash } => Self {
+                name,
+                package,
+                version_req,
+                version,
+                version_link,
+                hash,
+            },
+            ZomeDependencyFormat::Legacy( name, package, version, hash ) => Self {
+                name,
+                package,
+                version_req: format!("={}", version ),
+                version: None,
+                version_link: None,
+                hash,
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct YankNotice {
+    pub reason: String,
+    /// The version that should be used instead
+    pub recommended_version: Option<String>,
+}
+
 
 //
 // Zome Package Version Entry
@@ -56,10 +127,26 @@ pub struct ZomePackageVersionEntry {
     pub readme: Option<EntryHash>, // Mere memory addr for README.md
     pub changelog: Option<EntryHash>,
     pub source_code_revision_uri: Option<String>,
+    /// How the WASM was built; independent rebuilds are recorded as `RebuildAttestationEntry`s
+    #[serde(default)]
+    pub build_provenance: Option<BuildProvenance>,
     pub api_compatibility: ApiCompatibility,
     /// Used by coordinator zomes to indicate integrity or the expected peer coordinators that are
     /// called.
-    pub dependencies: Option<Vec<(String, ActionHash, String, String)>>, // (name, id, version, hash)
+    ///
+    /// Create
+    ///   - Each package must exist and the pinned version must belong to it
+    ///   - The pinned version's tag link must satisfy the version requirement
+    ///   - The pinned version's WASM hash must match the expected hash
+    /// Update
+    ///   - Cannot be updated
+    pub dependencies: Option<Vec<ZomeDependency>>,
+
+    // State
+    /// Set when this version has been yanked; it is skipped by version resolution but can still be
+    /// fetched by ID so that existing builds remain reproducible
+    #[serde(default)]
+    pub yanked: Option<YankNotice>,
 
     // Common fields
     pub metadata: BTreeMap<String, rmpv::Value>,
@@ -15,12 +15,15 @@ import {
 import {
     Link,
     ZomePackageVersionEntry,
+    ResolvedZomeDependency,
 
     // Entity Classes
     Zome,
     ZomeAsset,
     ZomePackage,
     ZomePackageVersion,
+    Review,
+    RebuildAttestation,
 }					from './types.js';
 
 
@@ -32,6 +35,20 @@ export const ZOME_TYPE_NAMES		= Object.values( ZOME_TYPES );
 
 const encoder                           = new TextEncoder();
 
+function zome_package_ref ( input ) {
+    try {
+	return {
+	    "type":	"id",
+	    "content":	new ActionHash( input ),
+	};
+    } catch (err) {
+	return {
+	    "type":	"name",
+	    "content":	input,
+	};
+    }
+}
+
 export const ZomeHubCSRZomelet		= new Zomelet({
     "whoami": {
 	output ( response ) {
@@ -117,11 +134,28 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 
 	return new Zome( result, this );
     },
+    async ingest_zome ( input ) {
+	if ( !ZOME_TYPE_NAMES.includes( input.zome_type ) )
+	    throw new TypeError(`Invalid 'zome_type' input '${input.zome_type}'; expected ${ZOME_TYPE_NAMES.join(", ")}`);
+
+	input.mere_memory_addr		= new EntryHash( input.mere_memory_addr );
+
+	const result			= await this.call( input );
+
+	result.zome			= new Zome( result.zome, this );
+
+	return result;
+    },
     async get_zome_entry ( input ) {
 	const result			= await this.call( new AnyDhtHash( input ) );
 
 	return new Zome( result, this );
     },
+    async get_zome_entries_by_hash ( input ) {
+	const result			= await this.call( input );
+
+	return result.map( entry => new Zome( entry, this ) );
+    },
     async get_zome_asset ( input ) {
 	const result			= await this.call( new EntryHash( input ) );
 
@@ -146,6 +180,23 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 	    })
 	);
     },
+    async get_zome_entries_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new Zome( entry, this ) );
+
+	return result;
+    },
+    async get_agent_storage_usage ( input ) {
+	const result			= await this.call( input ? new AgentPubKey( input ) : null );
+
+	result.agent			= new AgentPubKey( result.agent );
+
+	return result;
+    },
     async delete_zome ( input ) {
 	return new ActionHash( await this.call( new ActionHash( input ) ) );
     },
@@ -199,6 +250,60 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 
 	return zome_package;
     },
+    async deprecate_zome_package ( input ) {
+        input.properties                = input.properties || {};
+
+        if ( input.properties.maintainer === undefined ) {
+            const prev_zome_pack        = await this.functions.get_zome_package_entry( input.base );
+            input.properties.maintainer = prev_zome_pack.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const zome_package              = new ZomePackage( result, this );
+
+        if ( zome_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": zome_package.maintainer.content[0],
+                "content_id": zome_package.$id,
+                "content_prev": input.base,
+                "content_next": zome_package.$action,
+            });
+        }
+
+	return zome_package;
+    },
+    async undeprecate_zome_package ( input ) {
+        input.properties                = input.properties || {};
+
+        if ( input.properties.maintainer === undefined ) {
+            const prev_zome_pack        = await this.functions.get_zome_package_entry( input.base );
+            input.properties.maintainer = prev_zome_pack.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const zome_package              = new ZomePackage( result, this );
+
+        if ( zome_package.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": zome_package.maintainer.content[0],
+                "content_id": zome_package.$id,
+                "content_prev": input.base,
+                "content_next": zome_package.$action,
+            });
+        }
+
+	return zome_package;
+    },
     async get_zome_package ( input ) {
 	const result			= await this.call( new ActionHash( input ) );
 
@@ -214,11 +319,51 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 
 	return new ZomePackage( result, this );
     },
-    async get_all_zome_package_links () {
-	const links			= await this.call();
+    async get_all_zome_package_links ( include_deprecated ) {
+	const links			= await this.call( include_deprecated === true );
 
         return links.map( data => new Link(data) );
     },
+    async search_zome_packages ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input || {},
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new ZomePackage( entry, this ) );
+
+	return result;
+    },
+    async search_zome_packages_by_name_prefix ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entry => new ZomePackage( entry, this ) );
+    },
+    async search_zome_packages_by_name_fuzzy ( input ) {
+	const entries			= await this.call( input );
+
+	return entries.map( entry => new ZomePackage( entry, this ) );
+    },
+    async get_all_zome_packages_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input === true,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new ZomePackage( entry, this ) );
+
+	return result;
+    },
+    async get_zome_packages_for_agent_paginated ( input = {} ) {
+	const result			= await this.call({
+	    "input": input.input ? new AgentPubKey( input.input ) : null,
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( entry => new ZomePackage( entry, this ) );
+
+	return result;
+    },
     async get_zome_packages_for_agent ( input ) {
 	const entries			= await this.call( input ? new AgentPubKey( input ) : input );
 
@@ -242,6 +387,31 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 
 	return version_map;
     },
+    async get_zome_package_versions_paginated ( input ) {
+	const result			= await this.call({
+	    "input": new ActionHash( input.input ),
+	    "page": input.page || {},
+	});
+
+	result.items			= result.items.map( ([ vtag, pack_version ]) => {
+	    const version		= new ZomePackageVersion( pack_version, this );
+	    version.version		= vtag;
+
+	    return [ vtag, version ];
+	});
+
+	return result;
+    },
+    async get_zome_package_versions_including_yanked ( input ) {
+	const version_map		= await this.call( input );
+
+	for ( let [vtag, pack_version] of Object.entries(version_map) ) {
+	    version_map[ vtag ]		= new ZomePackageVersion( pack_version, this );
+	    version_map[ vtag ].version	= vtag;
+	}
+
+	return version_map;
+    },
     async delete_zome_package ( input ) {
 	return await this.call( input );
     },
@@ -332,8 +502,30 @@ export const ZomeHubCSRZomelet		= new Zomelet({
     },
     async get_zome_package_version ( input ) {
 	const result			= await this.call( new ActionHash( input ) );
+	const zome_version		= new ZomePackageVersion( result.version, this );
 
-	return new ZomePackageVersion( result, this );
+	zome_version.matching_rebuild_attestations	= result.matching_rebuild_attestations;
+
+	return zome_version;
+    },
+    async get_zome_package_version_by_semver ( input ) {
+	const [ vtag, result ]		= await this.call({
+	    "for_package":	zome_package_ref( input.for_package ),
+	    "version_req":	input.version_req,
+	});
+	const zome_version		= new ZomePackageVersion( result, this );
+
+	zome_version.version		= vtag;
+
+	return zome_version;
+    },
+    async get_latest_zome_package_version ( input ) {
+	const [ vtag, result ]		= await this.call( zome_package_ref( input ) );
+	const zome_version		= new ZomePackageVersion( result, this );
+
+	zome_version.version		= vtag;
+
+	return zome_version;
     },
     async update_zome_package_version ( input ) {
         if ( input.properties.maintainer === undefined ) {
@@ -376,10 +568,142 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 
 	return zome_package;
     },
+    async yank_zome_package_version ( input ) {
+        input.properties                = input.properties || {};
+
+        if ( input.properties.maintainer === undefined ) {
+            const prev_zome_pack_vers   = await this.functions.get_zome_package_version_entry( input.base );
+            const zome_package          = await this.functions.get_zome_package( prev_zome_pack_vers.for_package );
+            input.properties.maintainer = zome_package.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const zome_pack_version         = new ZomePackageVersion( result, this );
+
+        if ( zome_pack_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": zome_pack_version.maintainer.content[0],
+                "content_id": zome_pack_version.$id,
+                "content_prev": input.base,
+                "content_next": zome_pack_version.$action,
+            });
+        }
+
+	return zome_pack_version;
+    },
+    async resolve_zome_dependency_tree ( input ) {
+	const dependencies		= await this.call( new ActionHash( input ) );
+
+	return dependencies.map( dependency => ResolvedZomeDependency( dependency ) );
+    },
+    async get_dependents ( input ) {
+	const versions			= await this.call( new ActionHash( input ) );
+
+	return versions.map( version => new ZomePackageVersion( version, this ) );
+    },
+    async unyank_zome_package_version ( input ) {
+        input.properties                = input.properties || {};
+
+        if ( input.properties.maintainer === undefined ) {
+            const prev_zome_pack_vers   = await this.functions.get_zome_package_version_entry( input.base );
+            const zome_package          = await this.functions.get_zome_package( prev_zome_pack_vers.for_package );
+            input.properties.maintainer = zome_package.maintainer;
+
+            if ( input.properties.maintainer.type === "group" ) {
+                const group             = await this.zomes.coop_content_csr.get_group( input.properties.maintainer.content[0] );
+                input.properties.maintainer.content[1] = group.$action;
+            }
+        }
+
+	const result			= await this.call( input );
+        const zome_pack_version         = new ZomePackageVersion( result, this );
+
+        if ( zome_pack_version.maintainer.type === "group" ) {
+            await this.zomes.coop_content_csr.create_content_update_link({
+                "group_id": zome_pack_version.maintainer.content[0],
+                "content_id": zome_pack_version.$id,
+                "content_prev": input.base,
+                "content_next": zome_pack_version.$action,
+            });
+        }
+
+	return zome_pack_version;
+    },
     async delete_zome_package_version ( input ) {
 	return await this.call( input );
     },
 
+    //
+    // Review
+    //
+    async create_review ( input ) {
+	const result			= await this.call({
+	    "for_version":	new ActionHash( input.for_version ),
+	    "verdict":		input.verdict,
+	    "message":		input.message,
+	    "security_score":	input.security_score ?? null,
+	});
+
+	return new Review( result, this );
+    },
+    async get_review ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new Review( result, this );
+    },
+    async get_reviews_for_version ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return result.map( entry => new Review( entry, this ) );
+    },
+    async get_reviews_for_agent ( input ) {
+	const result			= await this.call( input ? new AgentPubKey( input ) : null );
+
+	return result.map( entry => new Review( entry, this ) );
+    },
+    async get_review_summary ( input ) {
+	return await this.call( new ActionHash( input ) );
+    },
+    async update_review ( input ) {
+	const result			= await this.call( input );
+
+	return new Review( result, this );
+    },
+    async delete_review ( input ) {
+	return new ActionHash( await this.call( new ActionHash( input ) ) );
+    },
+
+    //
+    // Rebuild Attestation
+    //
+    async submit_rebuild_attestation ( input ) {
+	const result			= await this.call({
+	    "for_version":	new ActionHash( input.for_version ),
+	    "hash":		input.hash,
+	    "build_provenance":	input.build_provenance ?? null,
+	});
+
+	return new RebuildAttestation( result, this );
+    },
+    async get_rebuild_attestation ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return new RebuildAttestation( result, this );
+    },
+    async get_rebuild_attestations_for_version ( input ) {
+	const result			= await this.call( new ActionHash( input ) );
+
+	return result.map( entry => new RebuildAttestation( entry, this ) );
+    },
+    async delete_rebuild_attestation ( input ) {
+	return new ActionHash( await this.call( new ActionHash( input ) ) );
+    },
+
 
     //
     // Virtual functions
@@ -425,9 +749,10 @@ export const ZomeHubCSRZomelet		= new Zomelet({
 	return await this.zomes.mere_memory_api.remember( zome_entry.mere_memory_addr );
     },
     async get_zome_by_wasm_hash ( input ) {
-        const my_zomes                  = await this.functions.get_zome_entries_for_agent();
-        const matching_zome             = Object.values( my_zomes )
-            .find( zome => zome.hash === input.hash );
+        const zomes                     = await this.functions.get_zome_entries_by_hash( input.hash );
+        const matching_zome             = zomes.find(
+            zome => !input.zome_type || zome.zome_type === input.zome_type
+        ) || zomes[0];
 
         if ( input.zome_type && matching_zome && matching_zome.zome_type !== input.zome_type )
             throw new TypeError(`Existing zome with WASM hash '${input.hash}' has a different zome type; found '${matching_zome.zome_type}' but expected '${input.zome_type}'`);
@@ -23,6 +23,8 @@ export const ZomeStruct = {
     "mere_memory_addr":		EntryHash,
     "file_size":		Number,
     "hash":			String,
+    "exports":			VecType( String ),
+    "detected_zome_type":	OptionType( String ),
 };
 
 export function ZomeEntry ( entry ) {
@@ -68,6 +70,7 @@ export const ZomePackageStruct = {
     "maintainer":               Authority,
     "tags":			OptionType( VecType( String ) ),
     "metadata":			Object,
+    "deprecation":		OptionType( Object ),
 };
 
 export function ZomePackageEntry ( entry ) {
@@ -131,10 +134,45 @@ export class Link {
 //
 // ZomePackageVersionEntry Handling
 //
+export const ZomeDependencyStruct = {
+    "name":			String,
+    "package":			ActionHash,
+    "version_req":		String,
+    "version":			OptionType( ActionHash ),
+    "version_link":		OptionType( ActionHash ),
+    "hash":			String,
+};
+
+export const ResolvedZomeDependencyStruct = {
+    "name":			String,
+    "package":			ActionHash,
+    "version":			String,
+    "version_id":		ActionHash,
+    "zome_type":		String,
+    "zome_entry":		EntryHash,
+    "hash":			String,
+    "yanked":			OptionType( Object ),
+};
+
+export function ResolvedZomeDependency ( entry ) {
+    return intoStruct( entry, ResolvedZomeDependencyStruct );
+}
+
+export const BuildProvenanceStruct = {
+    "repository_url":		String,
+    "commit_hash":		String,
+    "toolchain_versions":	MapType( String, String ),
+    "build_command":		String,
+    "flake_lock_hash":		OptionType( String ),
+};
+
 export const ZomePackageVersionStruct = {
     // The version value comes from the link tag (not the entry) so it will only be present when
     // fetched in the context of a 'get_links'
     "version":			OptionType( String ),
+    // The attestation count is calculated by the version getter so it will only be present when
+    // fetched using 'get_*_package_version'
+    "matching_rebuild_attestations":	OptionType( Number ),
 
     "for_package":		ActionHash,
     "zome_entry":		EntryHash,
@@ -142,6 +180,7 @@ export const ZomePackageVersionStruct = {
     "readme":		        OptionType( EntryHash ),
     "changelog":		OptionType( EntryHash ),
     "source_code_revision_uri":	OptionType( String ),
+    "build_provenance":		OptionType( BuildProvenanceStruct ),
     "api_compatibility": {
         "build_with": {
             "hdi_version":      String,
@@ -149,7 +188,8 @@ export const ZomePackageVersionStruct = {
         },
         "tested_with":          String,
     },
-    "dependencies":	        OptionType( VecType([ String, ActionHash, String, String ]) ),
+    "dependencies":	        OptionType( VecType( ZomeDependencyStruct ) ),
+    "yanked":			OptionType( Object ),
     "metadata":			Object,
 };
 
@@ -175,6 +215,45 @@ export class ZomePackageVersion extends ScopedEntity {
 }
 
 
+//
+// ReviewEntry Handling
+//
+export const ReviewStruct = {
+    "reviewer":			AgentPubKey,
+    "for_version":		ActionHash,
+    "verdict":			String,
+    "message":			String,
+    "security_score":		OptionType( Number ),
+};
+
+export function ReviewEntry ( entry ) {
+    return intoStruct( entry, ReviewStruct );
+}
+
+export class Review extends ScopedEntity {
+    static STRUCT		= ReviewStruct;
+}
+
+
+//
+// RebuildAttestationEntry Handling
+//
+export const RebuildAttestationStruct = {
+    "attester":			AgentPubKey,
+    "for_version":		ActionHash,
+    "hash":			String,
+    "build_provenance":		OptionType( BuildProvenanceStruct ),
+};
+
+export function RebuildAttestationEntry ( entry ) {
+    return intoStruct( entry, RebuildAttestationStruct );
+}
+
+export class RebuildAttestation extends ScopedEntity {
+    static STRUCT		= RebuildAttestationStruct;
+}
+
+
 export default {
     ZomeStruct,
     ZomeEntry,
@@ -190,4 +269,13 @@ export default {
 
     ZomeAssetStruct,
     ZomeAsset,
+
+    ReviewStruct,
+    ReviewEntry,
+    Review,
+
+    BuildProvenanceStruct,
+    RebuildAttestationStruct,
+    RebuildAttestationEntry,
+    RebuildAttestation,
 };
@@ -76,8 +76,111 @@ export default function ( args_fn ) {
 	expect( apps			).to.have.length( 1 );
     });
 
+    it("should create the same App", async function () {
+	const bundle			= Bundle.createHapp( TEST_HAPP_CONFIG );
+	const bundle_bytes		= bundle.toBytes();
+
+	const app			= await apphub_csr.save_app( bundle_bytes );
+
+	expect( app.$id			).to.deep.equal( app1.$id );
+    });
+
+    it("should get App entries by token", async function () {
+	const apps			= await apphub_csr.get_app_entries_by_token( app1.app_token );
+
+	expect( apps			).to.have.length( 1 );
+	expect( apps[0].$id		).to.deep.equal( app1.$id );
+    });
+
+    it("should get Apps with the same integrity hash", async function () {
+	const apps			= await apphub_csr.get_apps_with_integrity_hash( app1.app_token.integrity_hash );
+
+	expect( apps			).to.have.length( 1 );
+	expect( apps[0].$id		).to.deep.equal( app1.$id );
+    });
+
+    it("should diff App entries with a coordinator change", async function () {
+	const dna_config		= dnaConfig();
+	dna_config.coordinator.zomes[0].bytes = new Uint8Array( Array( 1_000 ).fill( 2 ) );
+
+	const bundle			= Bundle.createHapp( happConfig([{
+	    "name": "fake-role-1",
+	    "dna": {
+		"bytes": Bundle.createDna( dna_config ).toBytes(),
+	    },
+	}]) );
+	const app2			= await apphub_csr.save_app( bundle.toBytes() );
+	const diff			= await apphub_csr.diff_app_entries({
+	    "from": app1.$addr,
+	    "to": app2.$addr,
+	});
+	log.normal("App diff: %s", json.debug(diff) );
+
+	expect( diff.network_compatible			).to.be.true;
+	expect( diff.coordinator_update			).to.be.true;
+	expect( diff.added_roles			).to.have.length( 0 );
+	expect( diff.removed_roles			).to.have.length( 0 );
+	expect( diff.changed_roles			).to.have.length( 1 );
+	expect( diff.changed_roles[0].coordinators_changed	).to.be.true;
+	expect( diff.changed_roles[0].integrity_changed		).to.be.false;
+    });
+
+    it("should verify App entry against dnahub", async function () {
+	const report			= await apphub_csr.verify_app( app1.$addr );
+
+	log.normal("App verification report: %s", json.debug(report) );
+
+	expect( report.valid		).to.be.true;
+	expect( report.roles		).to.have.length( 1 );
+    });
+
     linearSuite("Errors", function () {
 
+	it("should fail to get hApp bundle because DNA tokens are fabricated", async function () {
+	    const entry			= await apphub_csr.get_app_entry( app1.$addr );
+	    const app			= await apphub_csr.create_app({
+		"manifest": entry.manifest,
+		"resources": entry.resources,
+		"roles_dna_tokens": {
+		    "fake-role-1": {
+			"integrity_hash": crypto.randomBytes( 32 ),
+			"integrities_token_hash": crypto.randomBytes( 32 ),
+			"coordinators_token_hash": crypto.randomBytes( 32 ),
+		    },
+		},
+		"claimed_file_size": entry.claimed_file_size,
+	    });
+
+	    const report		= await apphub_csr.verify_app( app.$addr );
+
+	    expect( report.valid		).to.be.false;
+	    expect( report.roles[0].errors	).to.have.length( 1 );
+
+	    await expect_reject(async () => {
+		await apphub_csr.get_happ_bundle( app.$addr );
+	    }, "failed verification" );
+	});
+
+	it("should fail to create App because DNA tokens are fabricated (strict)", async function () {
+	    const entry			= await apphub_csr.get_app_entry( app1.$addr );
+
+	    await expect_reject(async () => {
+		await apphub_csr.create_app({
+		    "manifest": entry.manifest,
+		    "resources": entry.resources,
+		    "roles_dna_tokens": {
+			"fake-role-1": {
+			    "integrity_hash": crypto.randomBytes( 32 ),
+			    "integrities_token_hash": crypto.randomBytes( 32 ),
+			    "coordinators_token_hash": crypto.randomBytes( 32 ),
+			},
+		    },
+		    "claimed_file_size": entry.claimed_file_size,
+		    "strict": true,
+		});
+	    }, "App failed verification" );
+	});
+
 	it("should fail to create App entry because of wrong invalid App token", async function () {
 	    await expect_reject(async () => {
 		const entry		= await apphub_csr.get_app_entry( app1.$addr );
@@ -60,6 +60,20 @@ export default function ( args_fn ) {
 	expect( uis			).to.have.length( 1 );
     });
 
+    it("should create the same UI", async function () {
+	const ui			= await apphub_csr.save_ui( UI_BYTES );
+
+	expect( ui.$id			).to.deep.equal( ui1.$id );
+    });
+
+    it("should get UI entries by hash", async function () {
+	const hash			= await apphub.zomes.mere_memory_api.functions.calculate_hash( UI_BYTES );
+	const uis			= await apphub_csr.get_ui_entries_by_hash( hash );
+
+	expect( uis			).to.have.length( 1 );
+	expect( uis[0].$id		).to.deep.equal( ui1.$id );
+    });
+
     it("should get UI asset", async function () {
 	const ui_asset			= await apphub_csr.get_ui_asset( ui1.$addr );
 	log.normal("%s", json.debug(ui_asset) );
@@ -128,6 +128,27 @@ export default function ( args_fn ) {
 	]);
     });
 
+    it("should get compatible app versions", async function () {
+	const versions			= await apphub_csr.get_compatible_app_versions( pack1_v1.$id );
+	const version_ids		= versions.map( packv => String( packv.$id ) );
+
+	log.normal("Compatible versions: %s", json.debug(versions) );
+
+	expect( versions		).to.have.length.at.least( 5 );
+	expect( version_ids		).to.not.include( String( pack1_v1.$id ) );
+    });
+
+    it("should diff WebApp Package Versions", async function () {
+	const diff			= await apphub_csr.diff_webapp_package_versions({
+	    "from": pack1_v1.$id,
+	    "to": moved_version.$id,
+	});
+
+	expect( diff.ui_changed			).to.be.false;
+	expect( diff.app.changed_roles		).to.have.length( 0 );
+	expect( diff.app.coordinator_update	).to.be.true;
+    });
+
     it("should get WebApp Package's version links", async function () {
 	const version_links		= await apphub_csr.get_webapp_package_version_links( pack1.$id );
 
@@ -31,6 +31,7 @@ export default function ( args_fn ) {
 
     let pack1;
     let bobby_client, bobby_apphub_csr;
+    let alice_coop_content, bobby_coop_content;
 
     before(async function () {
 	({
@@ -48,9 +49,11 @@ export default function ( args_fn ) {
 
 	const app_token			= installations.bobby.test.auth.token;
 	bobby_client			= await client.app( app_token );
-	bobby_apphub_csr		= bobby_client
-	      .createCellInterface( "apphub", AppHubCell )
-	      .zomes.apphub_csr.functions;
+
+	const bobby_apphub		= bobby_client.createCellInterface( "apphub", AppHubCell );
+	bobby_apphub_csr		= bobby_apphub.zomes.apphub_csr.functions;
+	bobby_coop_content		= bobby_apphub.zomes.coop_content_csr.functions;
+	alice_coop_content		= apphub.zomes.coop_content_csr.functions;
     });
 
     it("should create WebApp Package entry", async function () {
@@ -73,6 +76,25 @@ export default function ( args_fn ) {
 	expect( result			).to.have.length( 1 );
     });
 
+    it("should get all WebApp Packages paginated", async function () {
+	const page			= await apphub_csr.get_all_webapp_packages_paginated({
+	    "limit": 10,
+	});
+
+	expect( page.items		).to.have.length( 1 );
+	expect( page.items[0]		).to.be.a("WebAppPackage");
+	expect( page.next_cursor	).to.be.null;
+    });
+
+    it("should search WebApp Packages by name prefix", async function () {
+	const result			= await apphub_csr.search_webapp_packages_by_name_prefix(
+	    pack1.title.slice( 0, 5 ).toUpperCase()
+	);
+
+	expect( result			).to.have.length( 1 );
+	expect( result[0].$id		).to.deep.equal( pack1.$id );
+    });
+
     it("should update WebApp Package", async function () {
 	const prev_pack			= pack1.toJSON();
 
@@ -105,6 +127,89 @@ export default function ( args_fn ) {
 	expect( all_apps		).to.have.length( 0 );
     });
 
+    linearSuite("Group maintainer", function () {
+	let group1;
+	let group_pack;
+
+	it("should create WebApp Package maintained by a group", async function () {
+	    group1			= await alice_coop_content.create_group({
+		"admins":		[ app_client.agent_id ],
+		"members":		[],
+
+		"published_at":		Date.now(),
+		"last_updated":		Date.now(),
+		"metadata":		{},
+	    });
+
+	    group_pack			= await apphub_csr.create_webapp_package({
+		"title": faker.commerce.productName(),
+		"subtitle": faker.lorem.sentence(),
+		"description": faker.lorem.paragraphs( 2 ),
+		"icon": crypto.randomBytes( 1_000 ),
+		"source_code_uri": faker.internet.url(),
+		"maintainer": {
+		    "type":		"group",
+		    "content":		[ group1.$id, group1.$action ],
+		},
+	    });
+
+	    log.normal("Create group WebApp package: %s", json.debug(group_pack) );
+
+	    expect( group_pack.maintainer.type	).to.equal( "group" );
+	});
+
+	it("should fail to update group WebApp Package because not a group member", async function () {
+	    await expect_reject(async () => {
+		await bobby_apphub_csr.update_webapp_package({
+		    "base": group_pack.$action,
+		    "properties": {
+			"description": faker.lorem.paragraphs( 2 ),
+		    },
+		});
+	    }, "not authorized in group" );
+	});
+
+	it("should update group WebApp Package as a group member", async function () {
+	    group1			= await alice_coop_content.update_group({
+		"base": group1.$action,
+		"entry": Object.assign( {}, group1, {
+		    "members":		[ bobby_client.agent_id ],
+		    "last_updated":	Date.now(),
+		}),
+	    });
+
+	    await delay();
+
+	    group_pack			= await bobby_apphub_csr.update_webapp_package({
+		"base": group_pack.$action,
+		"properties": {
+		    "description": faker.lorem.paragraphs( 2 ),
+		},
+	    });
+
+	    const latest		= await apphub_csr.get_webapp_package( group_pack.$id );
+
+	    expect( latest.$action	).to.deep.equal( group_pack.$action );
+	});
+
+	it("should deprecate group WebApp Package as a group member", async function () {
+	    await delay();
+
+	    const deprecated		= await bobby_apphub_csr.deprecate_webapp_package({
+		"base": group_pack.$action,
+		"properties": {
+		    "message": "No longer maintained",
+		},
+	    });
+
+	    expect( deprecated.deprecation	).to.not.be.null;
+
+	    const all_apps		= await apphub_csr.get_all_webapp_packages();
+
+	    expect( all_apps.map( pack => String(pack.$id) ) ).to.not.include( String(group_pack.$id) );
+	});
+    });
+
     linearSuite("Errors", function () {
 
 	it("should fail to create WebApp Package entry because maintainer doesn't match create author", async function () {
@@ -114,7 +219,7 @@ export default function ( args_fn ) {
 		entry.maintainer.content = bobby_client.agent_id;
 
 		await apphub_csr.create_webapp_package_entry( entry );
-	    }, "Invalid maintainer" );
+	    }, "is not the maintainer" );
 	});
 
 	it("should fail to update WebApp Package because invalid maintainer", async function () {
@@ -125,7 +230,7 @@ export default function ( args_fn ) {
 			"description": faker.lorem.paragraphs( 2 ),
 		    },
 		});
-	    }, "Not authorized to update entry" );
+	    }, "is not the maintainer" );
 	});
 
 	it("should fail to update deprecated WebApp Package", async function () {
@@ -169,6 +169,12 @@ function basic_tests () {
 	const bundle			= new Bundle( bundle_bytes, "happ" );
 
 	log.normal("hApp bundle: %s", json.debug(bundle) );
+
+	for ( let role_manifest of bundle.manifest.roles ) {
+	    const dna_bundle		= new Bundle( bundle.resources[ role_manifest.dna.bundled ], "dna" );
+
+	    expect( dna_bundle.manifest.integrity.zomes ).to.have.length.above( 0 );
+	}
     });
 
     it("should get app asset", async function () {
@@ -214,6 +220,30 @@ function basic_tests () {
 	expect( webapp1.$addr		).to.be.a("EntryHash");
     });
 
+    it("should ingest WebApp bundle", async function () {
+	this.timeout( 10_000 );
+
+	// Use another agent so that the "my entries" counts in the suites are not affected
+	const bobby_client		= await client.app( installations.bobby.test.auth.token );
+	const bobby_apphub_csr		= bobby_client
+	      .createCellInterface( "apphub", AppHubCell )
+	      .zomes.apphub_csr.functions;
+
+	const bundle			= Bundle.createWebhapp( TEST_WEBHAPP_CONFIG );
+	const result			= await bobby_apphub_csr.save_bundle( bundle.toBytes() );
+
+	log.normal("Ingested bundle: %s", json.debug(result) );
+
+	expect( result.type			).to.equal("webhapp");
+	expect( result.content.webapp_entry	).to.be.a("WebApp");
+	expect( result.content.app.app_entry	).to.be.a("App");
+	expect( result.content.app.dnas		).to.have.all.keys("fake-role-1");
+
+	const happ_rpath		= bundle.manifest.happ_manifest.bundled;
+
+	expect( result.content.app.app_entry.claimed_file_size ).to.equal( bundle.resources[ happ_rpath ].length );
+    });
+
     it("should get WebApp entry", async function () {
 	const webapp			= await apphub_csr.get_webapp_entry( webapp1.$addr );
 
@@ -227,6 +257,15 @@ function basic_tests () {
 	log.normal("Webhapp bundle: %s", json.debug(bundle) );
     });
 
+    it("should verify WebApp entry", async function () {
+	const report			= await apphub_csr.verify_webapp( webapp1.$addr );
+
+	log.normal("WebApp verification report: %s", json.debug(report) );
+
+	expect( report.valid		).to.be.true;
+	expect( report.app.valid	).to.be.true;
+    });
+
     it("should get webapp asset", async function () {
 	const webapp_asset			= await apphub_csr.get_webapp_asset( webapp1.$addr );
 
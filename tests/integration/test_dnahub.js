@@ -8,6 +8,7 @@ import path				from 'path';
 import crypto				from 'crypto';
 
 import { expect }			from 'chai';
+import { faker }			from '@faker-js/faker';
 
 import json				from '@whi/json';
 import {
@@ -89,6 +90,8 @@ function basic_tests () {
     let dnahub;
     let dnahub_csr;
     let dna1;
+    let pack1;
+    let pack1_v1;
 
     before(async function () {
 	this.timeout( 30_000 );
@@ -130,6 +133,15 @@ function basic_tests () {
 	log.normal("DNA entry: %s", json.debug(dna) );
     });
 
+    it("should get agent storage usage", async function () {
+	const usage			= await dnahub_csr.get_agent_storage_usage();
+	log.trace("%s", json.debug(usage) );
+
+	expect( usage.entry_count	).to.equal( 1 );
+	expect( usage.total_file_size	).to.be.above( 0 );
+	expect( usage.quota.max_agent_storage	).to.be.null;
+    });
+
     it("should get some zome (with bytes)", async function () {
 	const zome			= await dnahub_csr.get_integrity_zome({
 	    "dna_entry": dna1.$addr,
@@ -139,11 +151,29 @@ function basic_tests () {
 	log.normal("ZOME [fake-zome-1]: %s", json.debug(zome) );
     });
 
+    it("should get DNA entries that depend on a zome", async function () {
+	const zome_hrl			= Object.values( dna1.resources )[0];
+	const dependents		= await dnahub_csr.get_dependents( zome_hrl.target );
+
+	log.normal("Dependents of zome (%s): %s", zome_hrl.target, json.debug(dependents) );
+
+	expect( dependents		).to.have.length( 1 );
+	expect( dependents[0].$id	).to.deep.equal( dna1.$id );
+    });
+
     it("should get DNA bundle", async function () {
 	const bundle_bytes		= await dnahub_csr.get_dna_bundle( dna1.$addr );
 	const bundle			= new Bundle( bundle_bytes, "dna" );
 
 	log.normal("DNA bundle: %s", json.debug(bundle) );
+
+	const original			= Bundle.createDna( TEST_DNA_CONFIG );
+
+	expect( bundle.manifest.name	).to.equal( original.manifest.name );
+
+	for ( let [rpath, bytes] of Object.entries( original.resources ) ) {
+	    expect( new Uint8Array( bundle.resources[ rpath ] ) ).to.deep.equal( new Uint8Array( bytes ) );
+	}
     });
 
     it("should get DNA asset", async function () {
@@ -169,6 +199,31 @@ function basic_tests () {
 	);
     });
 
+    it("should verify DNA entry against zomehub", async function () {
+	const report			= await dnahub_csr.verify_dna( dna1.$addr );
+
+	log.normal("DNA verification report: %s", json.debug(report) );
+
+	expect( report.valid		).to.be.true;
+	expect( report.zomes		).to.have.length( dna1.manifest.integrity.zomes.length + dna1.manifest.coordinator.zomes.length );
+    });
+
+    it("should get DNAs with the same integrity hash", async function () {
+	const dnas			= await dnahub_csr.get_dnas_with_integrity_hash( dna1.dna_token.integrity_hash );
+
+	expect( dnas			).to.have.length( 1 );
+	expect( dnas[0].$id		).to.deep.equal( dna1.$id );
+    });
+
+    it("should upload the same DNA bundle (strict)", async function () {
+	const bundle			= Bundle.createDna( TEST_DNA_CONFIG );
+	const bundle_bytes		= bundle.toBytes();
+
+	const dna			= await dnahub_csr.save_dna( bundle_bytes, { "strict": true } );
+
+	expect( dna.$addr		).to.deep.equal( dna1.$addr );
+    });
+
     it("should upload the same DNA bundle", async function () {
 	const bundle			= Bundle.createDna( TEST_DNA_CONFIG );
 	const bundle_bytes		= bundle.toBytes();
@@ -176,10 +231,131 @@ function basic_tests () {
 	const dna			= await dnahub_csr.save_dna( bundle_bytes );
 
 	expect( dna.$addr		).to.deep.equal( dna1.$addr );
+	expect( dna.$id			).to.deep.equal( dna1.$id );
+    });
+
+    it("should get DNA entries by token", async function () {
+	const dnas			= await dnahub_csr.get_dna_entries_by_token( dna1.dna_token );
+
+	expect( dnas			).to.have.length( 1 );
+	expect( dnas[0].$id		).to.deep.equal( dna1.$id );
+    });
+
+    it("should diff a coordinator update", async function () {
+	const config			= dnaConfig();
+	config.coordinator.zomes[0].bytes = new Uint8Array( Array( 1_000 ).fill( 2 ) );
+
+	const dna2			= await dnahub_csr.save_dna( Bundle.createDna( config ).toBytes() );
+	const diff			= await dnahub_csr.diff_dna_entries({
+	    "from": dna1.$addr,
+	    "to": dna2.$addr,
+	});
+	log.normal("DNA diff: %s", json.debug(diff) );
+
+	expect( diff.network_compatible		).to.be.true;
+	expect( diff.coordinator_update		).to.be.true;
+	expect( diff.integrity_zomes.changed	).to.have.length( 0 );
+	expect( diff.coordinator_zomes.changed	).to.deep.equal([ "fake-zome-2" ]);
+    });
+
+    it("should diff a network-breaking update", async function () {
+	const config			= dnaConfig();
+	config.integrity.origin_time	= "2024-01-01T00:00:00Z";
+
+	const dna2			= await dnahub_csr.save_dna( Bundle.createDna( config ).toBytes() );
+	const diff			= await dnahub_csr.diff_dna_entries({
+	    "from": dna1.$addr,
+	    "to": dna2.$addr,
+	});
+
+	expect( diff.network_compatible		).to.be.false;
+	expect( diff.coordinator_update		).to.be.false;
+	expect( diff.origin_time_changed	).to.be.true;
+	expect( diff.properties_changed		).to.be.false;
+    });
+
+    it("should create DNA package", async function () {
+	pack1				= await dnahub_csr.create_dna_package({
+	    "name":		faker.lorem.slug(),
+	    "title":		faker.commerce.productName(),
+	    "description":	faker.lorem.paragraphs( 2 ),
+	});
+
+	log.normal("DNA package: %s", json.debug(pack1) );
+
+	expect( pack1			).to.be.a("DnaPackage");
+	expect( pack1.maintainer.type	).to.equal("agent");
+    });
+
+    it("should get DNA package by name", async function () {
+	const dna_package		= await dnahub_csr.get_dna_package_by_name( pack1.name );
+
+	expect( dna_package.$id		).to.deep.equal( pack1.$id );
+    });
+
+    it("should search DNA packages by name prefix", async function () {
+	const dna_packages		= await dnahub_csr.search_dna_packages_by_name_prefix( pack1.name.slice( 0, 4 ) );
+
+	expect( dna_packages		).to.have.length( 1 );
+	expect( dna_packages[0].$id	).to.deep.equal( pack1.$id );
+    });
+
+    it("should search DNA packages by fuzzy name", async function () {
+	const dna_packages		= await dnahub_csr.search_dna_packages_by_name_fuzzy( pack1.name.slice( 0, -1 ) );
+
+	expect( dna_packages		).to.have.length( 1 );
+	expect( dna_packages[0].$id	).to.deep.equal( pack1.$id );
+    });
+
+    it("should create DNA package version", async function () {
+	pack1_v1			= await dnahub_csr.create_dna_package_version({
+	    "for_package":		pack1.$id,
+	    "version":			"0.1.0",
+	    "dna_entry":		dna1.$addr,
+	    "source_code_revision_uri":	faker.internet.url(),
+	});
+
+	log.normal("DNA package version: %s", json.debug(pack1_v1) );
+
+	expect( pack1_v1		).to.be.a("DnaPackageVersion");
+    });
+
+    it("should get DNA package versions", async function () {
+	const versions			= await dnahub_csr.get_dna_package_versions( pack1.$id );
+
+	expect( Object.keys( versions )	).to.deep.equal([ "0.1.0" ]);
+	expect( versions["0.1.0"].$id	).to.deep.equal( pack1_v1.$id );
     });
 
     linearSuite("Errors", function () {
 
+	it("should fail to create DNA package version because invalid version tag", async function () {
+	    await expect_reject(async () => {
+		await dnahub_csr.create_dna_package_version({
+		    "for_package":	pack1.$id,
+		    "version":		"latest",
+		    "dna_entry":	dna1.$addr,
+		});
+	    }, "is not a valid semver" );
+	});
+
+	it("should fail to update DNA package because not the maintainer", async function () {
+	    const app_token		= installations.bobby.test.auth.token;
+	    const bobby_client		= await client.app( app_token );
+	    const bobby_dnahub_csr	= bobby_client
+		  .createCellInterface( "dnahub", DnaHubCell )
+		  .zomes.dnahub_csr.functions;
+
+	    await expect_reject(async () => {
+		await bobby_dnahub_csr.update_dna_package({
+		    "base": pack1.$action,
+		    "properties": {
+			"title": faker.commerce.productName(),
+		    },
+		});
+	    }, "is not the maintainer" );
+	});
+
 	it("should fail to create DNA entry because of wrong invalid DNA token", async function () {
 	    await expect_reject(async () => {
 		const entry		= await dnahub_csr.get_dna_entry( dna1.$addr );
@@ -210,6 +386,23 @@ function basic_tests () {
 	    }, "Invalid Coordinators Token" );
 	});
 
+	it("should fail to create DNA because asset hash does not match zome (strict)", async function () {
+	    await expect_reject(async () => {
+		const entry		= await dnahub_csr.get_dna_entry( dna1.$addr );
+		const zome_name		= entry.manifest.integrity.zomes[0].name;
+
+		entry.asset_hashes.integrity[ zome_name ] = "0".repeat( 64 );
+
+		await dnahub_csr.create_dna({
+		    "manifest": entry.manifest,
+		    "resources": entry.resources,
+		    "claimed_file_size": entry.claimed_file_size,
+		    "asset_hashes": entry.asset_hashes,
+		    "strict": true,
+		});
+	    }, "DNA failed verification" );
+	});
+
 	it("should fail to update DNA entry");
 
 	it("should fail to delete DNA entry because author", async function () {
@@ -120,6 +120,8 @@ function real_tests () {
 	zome1_addr			= zome1.$addr;
 
 	expect( zome1_addr		).to.be.a("EntryHash");
+	expect( zome1.detected_zome_type	).to.equal("integrity");
+	expect( zome1.exports		).to.include("validate");
     });
 
     it("should get zome entry", async function () {
@@ -127,6 +129,33 @@ function real_tests () {
 	log.trace("%s", json.debug(zome) );
 
 	expect( zome			).to.have.any.keys( "mere_memory_addr" );
+	expect( zome.exports		).to.deep.equal( zome1.exports );
+    });
+
+    it("should create coordinator zome entry", async function () {
+	this.timeout( 10_000 );
+
+	const ZOME_PATH			= path.join( __dirname, "../../zomes/zomehub_csr.wasm" );
+	const zome_bytes		= await fs.readFile( ZOME_PATH );
+
+	const zome			= await zomehub_csr.save_coordinator( zome_bytes );
+
+	expect( zome.zome_type		).to.equal("coordinator");
+	expect( zome.detected_zome_type	).to.equal("coordinator");
+	// Inherited from the integrity dependency
+	expect( zome.exports		).to.include("entry_defs");
+	expect( zome.exports		).to.include("create_zome");
+    });
+
+    it("should fail to create coordinator zome from integrity wasm", async function () {
+	this.timeout( 10_000 );
+
+	const ZOME_PATH			= path.join( __dirname, "../../zomes/zomehub.wasm" );
+	const zome_bytes		= await fs.readFile( ZOME_PATH );
+
+	await expect_reject(async () => {
+	    await zomehub_csr.save_coordinator( zome_bytes );
+	}, "exports indicate 'integrity'" );
     });
 
     after(async function () {
@@ -36,6 +36,7 @@ import zome_package_versions_suite	from './zomehub/zome_package_versions_suite.j
 
 const __dirname				= path.dirname( new URL(import.meta.url).pathname );
 const ZOMEHUB_DNA_PATH			= path.join( __dirname, "../../dnas/zomehub.dna" );
+const ZOMEHUB_WASM_PATH			= path.join( __dirname, "../../zomes/zomehub.wasm" );
 
 const ZOMEHUB_DNA_NAME			= "zomehub";
 const MAIN_ZOME				= "zomehub_csr";
@@ -76,7 +77,7 @@ describe("ZomeHub", function () {
     });
 });
 
-const zome1_bytes			= crypto.randomBytes( 1_000 );
+const zome1_bytes			= await fs.readFile( ZOMEHUB_WASM_PATH );
 
 
 function basic_tests () {
@@ -138,10 +139,28 @@ function basic_tests () {
 	expect( zome_list		).to.have.length( 1 );
     });
 
+    it("should get agent storage usage", async function () {
+	const usage			= await zomehub_csr.get_agent_storage_usage();
+	log.trace("%s", json.debug(usage) );
+
+	expect( usage.entry_count	).to.equal( 1 );
+	expect( usage.total_file_size	).to.equal( zome1.file_size );
+	expect( usage.quota.max_file_size	).to.be.null;
+	expect( usage.quota.max_agent_storage	).to.be.null;
+    });
+
     it("should upload the same zome", async function () {
 	const zome			= await zomehub_csr.save_integrity( zome1_bytes );
 
 	expect( zome.$addr		).to.deep.equal( zome1_addr );
+	expect( zome.$id		).to.deep.equal( zome1.$id );
+    });
+
+    it("should get zome entries by hash", async function () {
+	const zomes			= await zomehub_csr.get_zome_entries_by_hash( zome1.hash );
+
+	expect( zomes			).to.have.length( 1 );
+	expect( zomes[0].$id		).to.deep.equal( zome1.$id );
     });
 
     it("should delete zome", async function () {
@@ -163,6 +182,26 @@ function basic_tests () {
 	    }, "file size does not match memory address" );
 	});
 
+	it("should fail to create zome because bytes are not wasm", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.save_integrity( crypto.randomBytes( 1_000 ) );
+	    }, "not a valid wasm module" );
+	});
+
+	it("should fail to create coordinator zome from integrity wasm", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.create_zome_entry({
+		    "zome_type": ZOME_TYPES.COORDINATOR,
+		    "mere_memory_addr": zome1.mere_memory_addr,
+		    "file_size": zome1.file_size,
+		    "hash": zome1.hash,
+		    // Replaced by the exports read from the wasm
+		    "exports": [ "create_zome" ],
+		    "detected_zome_type": ZOME_TYPES.COORDINATOR,
+		});
+	    }, "exports indicate 'integrity'" );
+	});
+
 	it("should fail to update zome entry");
 
 	it("should fail to delete zome entry because author", async function () {
@@ -71,6 +71,8 @@ describe("ZomeHub", function () {
 
     linearSuite("Phase 1 - Zome Package", phase1_tests );
     linearSuite("Phase 2 - Zome Package Version", phase2_tests );
+    linearSuite("Phase 3 - Reviews", phase3_tests );
+    linearSuite("Phase 4 - Rebuild Attestations", phase4_tests );
 
     after(async function () {
         await client.close();
@@ -413,3 +415,200 @@ function phase2_tests () {
     });
 
 }
+
+
+let pack2;
+let pack2_v1;
+let review1;
+
+function phase3_tests () {
+
+    before(async function () {
+        pack2                           = await alice_zomehub.create_zome_package({
+            "name":             faker.commerce.productName().toLowerCase(/\s/g, '-'),
+            "title":            faker.commerce.productName(),
+            "description":      faker.lorem.paragraphs( 2 ),
+            "zome_type":        "integrity",
+        });
+
+        pack2_v1                        = await alice_zomehub.create_zome_package_version({
+            "version": "0.1.0",
+            "for_package": pack2.$id,
+            "zome_entry": zome1.$addr,
+            "api_compatibility": {
+                "build_with": {
+                    "hdi_version": faker.system.semver(),
+                    "hdk_version": null,
+                },
+                "tested_with": faker.system.semver(),
+            },
+        });
+    });
+
+    it("(bobby) should review alice's zome package version", async function () {
+        review1                         = await bobby_zomehub.create_review({
+            "for_version":      pack2_v1.$id,
+            "verdict":          "approve",
+            "message":          faker.lorem.sentence(),
+            "security_score":   80,
+        });
+
+        log.normal("New review: %s", json.debug(review1) );
+
+        expect( review1                 ).to.be.a("Review");
+        expect( review1.reviewer        ).to.deep.equal( bobby_client.agent_id );
+    });
+
+    it("(alice) should get reviews for version", async function () {
+        const reviews                   = await alice_zomehub.get_reviews_for_version( pack2_v1.$id );
+        const bobbys_reviews            = await alice_zomehub.get_reviews_for_agent( bobby_client.agent_id );
+
+        expect( reviews                 ).to.have.length( 1 );
+        expect( reviews[0].$id          ).to.deep.equal( review1.$id );
+        expect( bobbys_reviews          ).to.have.length( 1 );
+    });
+
+    it("(alice) should get review summary", async function () {
+        const summary                   = await alice_zomehub.get_review_summary( pack2_v1.$id );
+
+        expect( summary.approvals       ).to.equal( 1 );
+        expect( summary.rejections      ).to.equal( 0 );
+        expect( summary.concerns        ).to.equal( 0 );
+        expect( summary.average_security_score ).to.equal( 80 );
+    });
+
+    it("(bobby) should update review", async function () {
+        const review                    = await bobby_zomehub.update_review({
+            "base": review1.$action,
+            "properties": {
+                "verdict":      "concern",
+            },
+        });
+
+        expect( review.verdict          ).to.equal( "concern" );
+        expect( review.security_score   ).to.equal( 80 );
+    });
+
+    describe("Errors", function () {
+
+        it("(alice) should fail to review own version", async function () {
+            await expect_reject(async () => {
+                await alice_zomehub.create_review({
+                    "for_version":      pack2_v1.$id,
+                    "verdict":          "approve",
+                    "message":          faker.lorem.sentence(),
+                });
+            }, "cannot review it" );
+        });
+
+        it("(bobby) should fail to review version twice", async function () {
+            await expect_reject(async () => {
+                await bobby_zomehub.create_review({
+                    "for_version":      pack2_v1.$id,
+                    "verdict":          "reject",
+                    "message":          faker.lorem.sentence(),
+                });
+            }, "has already reviewed version" );
+        });
+
+        it("(bobby) should fail to review with an invalid security score", async function () {
+            await expect_reject(async () => {
+                await bobby_zomehub.create_review({
+                    "for_version":      pack1_v1.$id,
+                    "verdict":          "reject",
+                    "message":          faker.lorem.sentence(),
+                    "security_score":   101,
+                });
+            }, "Security score must be between" );
+        });
+
+    });
+
+}
+
+
+let pack2_v2;
+let attestation1;
+
+function phase4_tests () {
+
+    before(async function () {
+        pack2_v2                        = await alice_zomehub.create_zome_package_version({
+            "version": "0.2.0",
+            "for_package": pack2.$id,
+            "zome_entry": zome1.$addr,
+            "build_provenance": {
+                "repository_url":       faker.internet.url(),
+                "commit_hash":          crypto.randomBytes( 20 ).toString("hex"),
+                "toolchain_versions": {
+                    "rustc":            "1.75.0",
+                },
+                "build_command":        "make zomes/zomehub.wasm",
+                "flake_lock_hash":      null,
+            },
+            "api_compatibility": {
+                "build_with": {
+                    "hdi_version": faker.system.semver(),
+                    "hdk_version": null,
+                },
+                "tested_with": faker.system.semver(),
+            },
+        });
+    });
+
+    it("(bobby) should attest rebuilding alice's zome package version", async function () {
+        attestation1                    = await bobby_zomehub.submit_rebuild_attestation({
+            "for_version":      pack2_v1.$id,
+            "hash":             zome1.hash,
+        });
+
+        log.normal("New rebuild attestation: %s", json.debug(attestation1) );
+
+        expect( attestation1            ).to.be.a("RebuildAttestation");
+        expect( attestation1.attester   ).to.deep.equal( bobby_client.agent_id );
+    });
+
+    it("(alice) should get version with matching attestation count", async function () {
+        const version                   = await alice_zomehub.get_zome_package_version( pack2_v1.$id );
+        const attestations              = await alice_zomehub.get_rebuild_attestations_for_version( pack2_v1.$id );
+
+        expect( version.matching_rebuild_attestations ).to.equal( 1 );
+        expect( attestations            ).to.have.length( 1 );
+        expect( attestations[0].$id     ).to.deep.equal( attestation1.$id );
+    });
+
+    it("(bobby) should attest a different hash without matching", async function () {
+        await bobby_zomehub.submit_rebuild_attestation({
+            "for_version":      pack2_v2.$id,
+            "hash":             crypto.randomBytes( 32 ).toString("hex"),
+        });
+
+        const version                   = await alice_zomehub.get_zome_package_version( pack2_v2.$id );
+
+        expect( version.build_provenance.toolchain_versions.rustc ).to.equal( "1.75.0" );
+        expect( version.matching_rebuild_attestations ).to.equal( 0 );
+    });
+
+    describe("Errors", function () {
+
+        it("(alice) should fail to attest own version", async function () {
+            await expect_reject(async () => {
+                await alice_zomehub.submit_rebuild_attestation({
+                    "for_version":      pack2_v1.$id,
+                    "hash":             zome1.hash,
+                });
+            }, "cannot attest it" );
+        });
+
+        it("(bobby) should fail to attest version twice", async function () {
+            await expect_reject(async () => {
+                await bobby_zomehub.submit_rebuild_attestation({
+                    "for_version":      pack2_v1.$id,
+                    "hash":             zome1.hash,
+                });
+            }, "has already attested version" );
+        });
+
+    });
+
+}
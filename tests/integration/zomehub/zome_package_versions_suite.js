@@ -27,6 +27,8 @@ export default function ( args_fn ) {
     let pack1;
     let pack1_dup;
     let pack1_v1;
+    let pack1_v2;
+    let pack1_dup_v1;
     let pack1_name;
 
     before(async function () {
@@ -101,7 +103,7 @@ export default function ( args_fn ) {
     }
 
     it("should get Zome Package versions (sorted with semver)", async function () {
-	await create_version("0.2.0");
+	pack1_v2			= await create_version("0.2.0");
 	await create_version("0.1.0-beta-rc.0");
 	await create_version("0.1.0-beta-rc.1");
 	await create_version("0.1.0-beta-rc.2");
@@ -122,6 +124,163 @@ export default function ( args_fn ) {
 	]);
     });
 
+    it("should get latest Zome Package version matching semver requirement", async function () {
+	const pre_release		= await zomehub_csr.get_zome_package_version_by_semver({
+	    "for_package": pack1.$id,
+	    "version_req": ">=0.1.0-beta-rc.0, <0.1.0-beta-rc.3",
+	});
+	const stable			= await zomehub_csr.get_zome_package_version_by_semver({
+	    "for_package": pack1.$id,
+	    "version_req": "^0.1",
+	});
+	const latest			= await zomehub_csr.get_latest_zome_package_version( pack1.$id );
+
+	expect( pre_release.version	).to.equal( "0.1.0-beta-rc.2" );
+	expect( stable.version		).to.equal( "0.1.0" );
+	expect( latest.version		).to.equal( "0.2.0" );
+	expect( latest			).to.be.a("ZomePackageVersion");
+    });
+
+    it("should exclude yanked Zome Package version from resolution", async function () {
+	const yanked			= await zomehub_csr.yank_zome_package_version({
+	    "base": pack1_v2.$action,
+	    "properties": {
+		"reason": "Broken build",
+		"recommended_version": "0.1.0",
+	    },
+	});
+
+	expect( yanked.yanked.reason	).to.equal( "Broken build" );
+
+	const latest			= await zomehub_csr.get_latest_zome_package_version( pack1.$id );
+	const versions			= await zomehub_csr.get_zome_package_versions( pack1.$id );
+	const all_versions		= await zomehub_csr.get_zome_package_versions_including_yanked( pack1.$id );
+
+	expect( latest.version		).to.equal( "0.1.0" );
+	expect( versions		).to.not.have.key( "0.2.0" );
+	expect( all_versions		).to.include.key( "0.2.0" );
+
+	// Exact ID lookups still resolve yanked versions
+	const by_id			= await zomehub_csr.get_zome_package_version( pack1_v2.$id );
+
+	expect( by_id.yanked		).to.not.be.null;
+    });
+
+    it("should unyank Zome Package version", async function () {
+	const version			= await zomehub_csr.get_zome_package_version( pack1_v2.$id );
+
+	await zomehub_csr.unyank_zome_package_version({
+	    "base": version.$action,
+	});
+
+	const latest			= await zomehub_csr.get_latest_zome_package_version( pack1.$id );
+
+	expect( latest.version		).to.equal( "0.2.0" );
+    });
+
+    it("should page through Zome Package versions", async function () {
+	const page1			= await zomehub_csr.get_zome_package_versions_paginated({
+	    "input": pack1.$id,
+	    "page": {
+		"limit": 4,
+	    },
+	});
+
+	log.normal("Zome package versions page: %s", json.debug(page1) );
+	expect( page1.items		).to.have.length( 4 );
+	expect( page1.items[0][1]	).to.be.a("ZomePackageVersion");
+	expect( page1.next_cursor	).to.not.be.null;
+
+	const page2			= await zomehub_csr.get_zome_package_versions_paginated({
+	    "input": pack1.$id,
+	    "page": {
+		"limit": 4,
+		"cursor": page1.next_cursor,
+	    },
+	});
+
+	expect( page2.items		).to.have.length( 2 );
+	expect( page2.next_cursor	).to.be.null;
+
+	const version_names		= [ ...page1.items, ...page2.items ].map( ([ vtag ]) => vtag );
+	expect( new Set( version_names ).size ).to.equal( 6 );
+
+	const descending		= await zomehub_csr.get_zome_package_versions_paginated({
+	    "input": pack1.$id,
+	    "page": {
+		"limit": 1,
+		"sort": "descending",
+	    },
+	});
+
+	expect( descending.items[0][0]	).to.equal( version_names[ version_names.length - 1 ] );
+    });
+
+    it("should create Zome Package Version with a dependency", async function () {
+	pack1_dup_v1			= await zomehub_csr.create_zome_package_version({
+	    "version": "0.1.0",
+	    "for_package": pack1_dup.$id,
+	    "zome_entry": zome1_addr,
+	    "api_compatibility": {
+		"build_with": {
+		    "hdi_version": faker.system.semver(),
+		    "hdk_version": null,
+		},
+		"tested_with": faker.system.semver(),
+	    },
+	    "dependencies": [{
+		"name": pack1_name,
+		"package": pack1.$id,
+		"version_req": "^0.2",
+		"version": pack1_v2.$id,
+		"hash": zome1.hash,
+	    }],
+	});
+
+	expect( pack1_dup_v1.dependencies	).to.have.length( 1 );
+	expect( pack1_dup_v1.dependencies[0].version_link ).to.not.be.null;
+    });
+
+    it("should resolve Zome dependency tree", async function () {
+	const dependencies		= await zomehub_csr.resolve_zome_dependency_tree( pack1_dup_v1.$id );
+
+	log.normal("Resolved dependencies: %s", json.debug(dependencies) );
+
+	expect( dependencies		).to.have.length( 1 );
+	expect( dependencies[0].version	).to.equal("0.2.0");
+	expect( dependencies[0].hash	).to.equal( zome1.hash );
+	expect( dependencies[0].yanked	).to.be.null;
+    });
+
+    it("should flag yanked pins in the resolved Zome dependency tree", async function () {
+	const latest			= await zomehub_csr.get_zome_package_version( pack1_v2.$id );
+	const version			= await zomehub_csr.yank_zome_package_version({
+	    "base": latest.$action,
+	    "properties": {
+		"reason": "Broken build",
+		"recommended_version": null,
+	    },
+	});
+
+	const dependencies		= await zomehub_csr.resolve_zome_dependency_tree( pack1_dup_v1.$id );
+
+	expect( dependencies[0].version	).to.equal("0.2.0");
+	expect( dependencies[0].yanked.reason ).to.equal("Broken build");
+
+	await zomehub_csr.unyank_zome_package_version({
+	    "base": version.$action,
+	});
+    });
+
+    it("should get Zome Package version dependents", async function () {
+	const dependents		= await zomehub_csr.get_dependents( pack1_dup_v1.dependencies[0].version );
+
+	log.normal("Dependents: %s", json.debug(dependents) );
+
+	expect( dependents		).to.have.length( 1 );
+	expect( dependents[0].$id	).to.deep.equal( pack1_dup_v1.$id );
+    });
+
     it("should get Zome Package's version links", async function () {
 	const version_links		= await zomehub_csr.get_zome_package_version_links( pack1.$id );
 
@@ -182,6 +341,93 @@ export default function ( args_fn ) {
 
     linearSuite("Errors", function () {
 
+	it("should fail to resolve version because no version matches", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.get_zome_package_version_by_semver({
+		    "for_package": pack1.$id,
+		    "version_req": "^1.0",
+		});
+	    }, "matches requirement" );
+	});
+
+	it("should fail to create version because dependency hash does not match", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.create_zome_package_version({
+		    "version": "0.1.1",
+		    "for_package": pack1_dup.$id,
+		    "zome_entry": zome1_addr,
+		    "api_compatibility": {
+			"build_with": {
+			    "hdi_version": faker.system.semver(),
+			    "hdk_version": null,
+			},
+			"tested_with": faker.system.semver(),
+		    },
+		    "dependencies": [{
+			"name": pack1_name,
+			"package": pack1.$id,
+			"version_req": "^0.2",
+			"version": pack1_v2.$id,
+			"hash": "not-the-hash",
+		    }],
+		});
+	    }, "hash does not match" );
+	});
+
+	it("should fail to create version because pinned version does not satisfy requirement", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.create_zome_package_version({
+		    "version": "0.1.2",
+		    "for_package": pack1_dup.$id,
+		    "zome_entry": zome1_addr,
+		    "api_compatibility": {
+			"build_with": {
+			    "hdi_version": faker.system.semver(),
+			    "hdk_version": null,
+			},
+			"tested_with": faker.system.semver(),
+		    },
+		    "dependencies": [{
+			"name": pack1_name,
+			"package": pack1.$id,
+			"version_req": "^0.1",
+			"version": pack1_v2.$id,
+			"hash": zome1.hash,
+		    }],
+		});
+	    }, "does not satisfy requirement" );
+	});
+
+	it("should fail to create version because dependency does not pin a version", async function () {
+	    await expect_reject(async () => {
+		await zomehub_csr.create_zome_package_version({
+		    "version": "0.1.3",
+		    "for_package": pack1_dup.$id,
+		    "zome_entry": zome1_addr,
+		    "api_compatibility": {
+			"build_with": {
+			    "hdi_version": faker.system.semver(),
+			    "hdk_version": null,
+			},
+			"tested_with": faker.system.semver(),
+		    },
+		    "dependencies": [{
+			"name": pack1_name,
+			"package": pack1.$id,
+			"version_req": "^0.2",
+			"version": null,
+			"hash": zome1.hash,
+		    }],
+		});
+	    }, "must pin a version" );
+	});
+
+	it("should fail to create version because tag is not a valid semver", async function () {
+	    await expect_reject(async () => {
+		await create_version("latest");
+	    }, "is not a valid semver" );
+	});
+
     });
 
 }
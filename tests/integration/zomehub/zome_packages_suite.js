@@ -78,8 +78,218 @@ export default function ( args_fn ) {
 	log.normal("Get Zome package: %s", json.debug(zome_package) );
     });
 
+    it("should search Zome Packages by name prefix", async function () {
+	const results			= await zomehub_csr.search_zome_packages_by_name_prefix( pack1_name.slice( 0, 4 ) );
+
+	log.normal("Search results: %s", json.debug(results) );
+
+	expect( results			).to.have.length( 1 );
+	expect( results[0].$id		).to.deep.equal( pack1.$id );
+
+	for ( let length of [ 2, 3 ] ) {
+	    const results		= await zomehub_csr.search_zome_packages_by_name_prefix( pack1_name.slice( 0, length ) );
+
+	    expect( results.map( entity => String(entity.$id) ) ).to.include( String(pack1.$id) );
+	}
+
+	await expect_reject(async () => {
+	    await zomehub_csr.search_zome_packages_by_name_prefix( " " );
+	}, "cannot be empty" );
+
+	await expect_reject(async () => {
+	    await zomehub_csr.search_zome_packages_by_name_prefix( pack1_name.slice( 0, 1 ) );
+	}, "at least 2 characters" );
+    });
+
+    it("should search Zome Packages by fuzzy name", async function () {
+	const results			= await zomehub_csr.search_zome_packages_by_name_fuzzy( pack1_name.slice( 0, -1 ) + "#" );
+
+	log.normal("Fuzzy search results: %s", json.debug(results) );
+
+	expect( results			).to.have.length( 1 );
+	expect( results[0].$id		).to.deep.equal( pack1.$id );
+
+	{
+	    // A typo after the shard characters still matches through the other trigrams
+	    const results		= await zomehub_csr.search_zome_packages_by_name_fuzzy(
+		pack1_name.slice( 0, 2 ) + "#" + pack1_name.slice( 3 )
+	    );
+
+	    expect( results.map( entity => String(entity.$id) ) ).to.include( String(pack1.$id) );
+	}
+    });
+
+    it("should search Zome Packages by tag", async function () {
+	pack1				= await zomehub_csr.update_zome_package({
+	    "base": pack1.$action,
+	    "properties": {
+		"tags": [ "Storage", "crud" ],
+	    },
+	});
+
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "tags": [ "storage" ],
+		},
+	    });
+	    log.normal("Search results: %s", json.debug(results) );
+
+	    expect( results		).to.have.length( 1 );
+	    expect( results[0].$id	).to.deep.equal( pack1.$id );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "tags": [ "storage", "unknown" ],
+		},
+	    });
+	    expect( results		).to.have.length( 0 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "name_prefix": pack1_name.slice( 0, 3 ),
+		    "zome_type": "integrity",
+		},
+	    });
+	    expect( results		).to.have.length( 1 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "name_prefix": pack1_name.slice( 0, 3 ).toUpperCase(),
+		},
+	    });
+	    expect( results		).to.have.length( 1 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "zome_type": "coordinator",
+		},
+	    });
+	    expect( results		).to.have.length( 0 );
+	}
+    });
+
+    it("should remove tag anchors when tags are updated", async function () {
+	pack1				= await zomehub_csr.update_zome_package({
+	    "base": pack1.$action,
+	    "properties": {
+		"tags": [ "crud" ],
+	    },
+	});
+
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "tags": [ "storage" ],
+		},
+	    });
+	    expect( results		).to.have.length( 0 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "tags": [ "crud" ],
+		},
+		"page": {
+		    "limit": 1,
+		},
+	    });
+	    expect( results		).to.have.length( 1 );
+	}
+    });
+
+    it("should deprecate Zome Package", async function () {
+	const zome_package		= await zomehub_csr.deprecate_zome_package({
+	    "base": pack1.$action,
+	    "properties": {
+		"message": "No longer maintained",
+	    },
+	});
+
+	log.normal("Deprecated Zome package: %s", json.debug(zome_package) );
+
+	expect( zome_package.deprecation.message ).to.equal("No longer maintained");
+
+	{
+	    const links			= await zomehub_csr.get_all_zome_package_links();
+	    expect( links		).to.have.length( 0 );
+	}
+	{
+	    const links			= await zomehub_csr.get_all_zome_package_links( true );
+	    expect( links		).to.have.length( 1 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "name_prefix": pack1_name.slice( 0, 3 ),
+		},
+	    });
+	    expect( results		).to.have.length( 0 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.search_zome_packages({
+		"input": {
+		    "name_prefix": pack1_name.slice( 0, 3 ),
+		    "include_deprecated": true,
+		},
+	    });
+	    expect( results		).to.have.length( 1 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.get_all_zome_packages_paginated();
+	    expect( results		).to.have.length( 0 );
+	}
+	{
+	    const { items: results }	= await zomehub_csr.get_all_zome_packages_paginated({
+		"input": true,
+	    });
+	    expect( results		).to.have.length( 1 );
+	}
+
+	pack1				= zome_package;
+    });
+
+    it("should undeprecate Zome Package", async function () {
+	const zome_package		= await zomehub_csr.undeprecate_zome_package({
+	    "base": pack1.$action,
+	});
+
+	expect( zome_package.deprecation	).to.be.null;
+
+	const links			= await zomehub_csr.get_all_zome_package_links();
+	expect( links			).to.have.length( 1 );
+
+	pack1				= zome_package;
+    });
+
     linearSuite("Errors", function () {
 
+	it("should fail to update deprecated Zome Package", async function () {
+	    const zome_package		= await zomehub_csr.deprecate_zome_package({
+		"base": pack1.$action,
+		"properties": {
+		    "message": "Temporarily deprecated",
+		},
+	    });
+
+	    await expect_reject(async () => {
+		await zomehub_csr.update_zome_package({
+		    "base": zome_package.$action,
+		    "properties": {
+			"title": "Updated title",
+		    },
+		});
+	    }, "Cannot update deprecated entity" );
+
+	    pack1			= await zomehub_csr.undeprecate_zome_package({
+		"base": zome_package.$action,
+	    });
+	});
+
     });
 
 }
@@ -29,6 +29,8 @@ pub enum EntryTypes {
     WebApp(WebAppEntry),
     WebAppPackage(WebAppPackageEntry),
     WebAppPackageVersion(WebAppPackageVersionEntry),
+    Review(ReviewEntry),
+    RebuildAttestation(RebuildAttestationEntry),
 }
 
 scoped_type_connector!(
@@ -51,6 +53,14 @@ scoped_type_connector!(
     EntryTypesUnit::WebAppPackageVersion,
     EntryTypes::WebAppPackageVersion( WebAppPackageVersionEntry )
 );
+scoped_type_connector!(
+    EntryTypesUnit::Review,
+    EntryTypes::Review( ReviewEntry )
+);
+scoped_type_connector!(
+    EntryTypesUnit::RebuildAttestation,
+    EntryTypes::RebuildAttestation( RebuildAttestationEntry )
+);
 
 // Entity implementations
 entry_model!( EntryTypes::App( AppEntry ) );
@@ -58,6 +68,8 @@ entry_model!( EntryTypes::Ui( UiEntry ) );
 entry_model!( EntryTypes::WebApp( WebAppEntry ) );
 entry_model!( EntryTypes::WebAppPackage( WebAppPackageEntry ) );
 entry_model!( EntryTypes::WebAppPackageVersion( WebAppPackageVersionEntry ) );
+entry_model!( EntryTypes::Review( ReviewEntry ) );
+entry_model!( EntryTypes::RebuildAttestation( RebuildAttestationEntry ) );
 
 
 
@@ -76,7 +88,23 @@ pub enum LinkTypes {
     AgentToWebAppPackage,
     AgentToWebAppPackageVersion,
 
+    NameIndexToWebAppPackage,
+
+    /// Tagged with the version; a tag is unique per author only, so the oldest claim wins
     WebAppPackageToWebAppPackageVersion,
+
+    DnaToApp,
+
+    AppTokenToApp,
+    UiHashToUi,
+
+    IntegrityHashToApp,
+    IntegrityHashToWebAppPackageVersion,
+
+    WebAppPackageVersionToReview,
+    AgentToReview,
+
+    WebAppPackageVersionToRebuildAttestation,
 }
 
 impl TryFrom<String> for LinkTypes {
@@ -97,8 +125,23 @@ impl TryFrom<String> for LinkTypes {
                 "AgentToWebAppPackage" => LinkTypes::AgentToWebAppPackage,
                 "AgentToWebAppPackageVersion" => LinkTypes::AgentToWebAppPackageVersion,
 
+                "NameIndexToWebAppPackage" => LinkTypes::NameIndexToWebAppPackage,
+
                 "WebAppPackageToWebAppPackageVersion" => LinkTypes::WebAppPackageToWebAppPackageVersion,
 
+                "DnaToApp" => LinkTypes::DnaToApp,
+
+                "AppTokenToApp" => LinkTypes::AppTokenToApp,
+                "UiHashToUi" => LinkTypes::UiHashToUi,
+
+                "IntegrityHashToApp" => LinkTypes::IntegrityHashToApp,
+                "IntegrityHashToWebAppPackageVersion" => LinkTypes::IntegrityHashToWebAppPackageVersion,
+
+                "WebAppPackageVersionToReview" => LinkTypes::WebAppPackageVersionToReview,
+                "AgentToReview" => LinkTypes::AgentToReview,
+
+                "WebAppPackageVersionToRebuildAttestation" => LinkTypes::WebAppPackageVersionToRebuildAttestation,
+
                 _ => return Err(guest_error!(format!("Unknown LinkTypes variant: {}", name ))),
             }
         )
@@ -3,18 +3,31 @@ use crate::{
     hdi_extensions,
     mere_memory_types,
     EntryTypes,
-    Authority,
+    UiEntry,
     WebAppEntry,
+    WebAppPackageEntry,
+    WebAppPackageVersionEntry,
+    validation::{
+        check_authority,
+    },
 };
 
 use hdi::prelude::*;
 use hdi_extensions::{
+
     // Macros
     valid, invalid,
 };
 use mere_memory_types::{
     MemoryEntry,
 };
+use devhub_types::{
+    check_maintainer_matches_package,
+    check_storage_quota,
+    check_review,
+    check_rebuild_attestation,
+    check_not_version_maintainer,
+};
 
 
 pub fn validation(
@@ -45,6 +58,13 @@ pub fn validation(
                 ))
             }
 
+            if let ValidateCallbackResult::Invalid(msg) = check_storage_quota(
+                &create, ui_entry.file_size,
+                |entry| Ok( UiEntry::try_from( entry )?.file_size ),
+            )? {
+                invalid!(msg)
+            }
+
             valid!()
         },
         EntryTypes::WebApp(webapp_entry) => {
@@ -57,29 +77,34 @@ pub fn validation(
             valid!()
         },
         EntryTypes::WebAppPackage(webapp_package_entry) => {
-            match webapp_package_entry.maintainer {
-                Authority::Agent(agent_id) => {
-                    if agent_id != create.author {
-                        invalid!(format!(
-                            "Invalid maintainer '{}'; must match the creating agent ({})",
-                            agent_id, create.author
-                        ))
-                    }
-                },
+            //
+            // Check if create author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_entry.maintainer, &create.author )? {
+                invalid!(msg)
             }
 
             valid!()
         },
         EntryTypes::WebAppPackageVersion(webapp_package_version_entry) => {
-            match webapp_package_version_entry.maintainer {
-                Authority::Agent(agent_id) => {
-                    if agent_id != create.author {
-                        invalid!(format!(
-                            "Invalid maintainer '{}'; must match the creating agent ({})",
-                            agent_id, create.author
-                        ))
-                    }
-                },
+            let webapp_package : WebAppPackageEntry = must_get_valid_record(
+                webapp_package_version_entry.for_package.to_owned()
+            )?.try_into()?;
+
+            //
+            // Check parent maintainer settings
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_matches_package(
+                &webapp_package.maintainer, &webapp_package_version_entry.maintainer,
+            )? {
+                invalid!(msg)
+            }
+
+            //
+            // Check if create author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_version_entry.maintainer, &create.author )? {
+                invalid!(msg)
             }
 
             let webapp_entry : WebAppEntry = must_get_entry( webapp_package_version_entry.webapp )?
@@ -92,6 +117,24 @@ pub fn validation(
 
             valid!()
         },
+        EntryTypes::Review(entry) => {
+            if let ValidateCallbackResult::Invalid(msg) = check_review( &entry, &create.author )? {
+                invalid!(msg)
+            }
+
+            let version : WebAppPackageVersionEntry = must_get_valid_record( entry.for_version.clone() )?.try_into()?;
+
+            check_not_version_maintainer( &entry.for_version, &version.maintainer, &create.author, "review" )
+        },
+        EntryTypes::RebuildAttestation(entry) => {
+            if let ValidateCallbackResult::Invalid(msg) = check_rebuild_attestation( &entry, &create.author )? {
+                invalid!(msg)
+            }
+
+            let version : WebAppPackageVersionEntry = must_get_valid_record( entry.for_version.clone() )?.try_into()?;
+
+            check_not_version_maintainer( &entry.for_version, &version.maintainer, &create.author, "attest" )
+        },
         // _ => invalid!(format!("Create validation not implemented for entry type: {:#?}", create.entry_type )),
     }
 }
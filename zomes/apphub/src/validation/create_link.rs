@@ -7,6 +7,12 @@ use crate::{
     WebAppEntry,
     WebAppPackageEntry,
     WebAppPackageVersionEntry,
+    ReviewEntry,
+    RebuildAttestationEntry,
+    validation::{
+        check_authority,
+        check_webapp_package_authority,
+    },
 };
 
 use hdi::prelude::*;
@@ -16,13 +22,18 @@ use hdi_extensions::{
     // Macros
     valid, invalid,
 };
+use devhub_types::{
+    parse_version_tag,
+    check_tag_unclaimed,
+    check_name_index_link,
+};
 
 
 pub fn validation(
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     link_type: LinkTypes,
-    _tag: LinkTag,
+    tag: LinkTag,
     create: CreateLink,
 ) -> ExternResult<ValidateCallbackResult> {
     match link_type {
@@ -35,6 +46,8 @@ pub fn validation(
             verify_app_entry_struct::<WebAppPackageEntry>( &base_address )?;
             verify_app_entry_struct::<WebAppPackageVersionEntry>( &target_address )?;
 
+            parse_version_tag( &tag )?;
+
             let webapp_package_id = base_address.must_be_action_hash()?;
             let webapp_package_version_addr = target_address.must_be_action_hash()?;
 
@@ -49,6 +62,40 @@ pub fn validation(
                 ))
             }
 
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_version_entry.maintainer, &create.author )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_tag_unclaimed( &create )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::NameIndexToWebAppPackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a WebAppPackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            // The indexed title can change with updates, so the tag cannot be checked against the
+            // original entry
+            WebAppPackageEntry::try_from( record )?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_name_index_link( &base_address, &tag )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_webapp_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
             valid!()
         },
         LinkTypes::AgentToApp => {
@@ -136,6 +183,187 @@ pub fn validation(
 
             valid!()
         },
+        LinkTypes::DnaToApp => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of an AppEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            if record.action().author() != &create.author {
+                invalid!(format!(
+                    "{:?} link target ({}) was not created by the link author",
+                    link_type, target_address
+                ))
+            }
+
+            let app_entry = AppEntry::try_from( record )?;
+
+            if !app_entry.resources.values()
+                .any( |hrl| AnyLinkableHash::from( hrl.target.clone() ) == base_address )
+            {
+                invalid!(format!(
+                    "{:?} link base ({}) is not a resource of App entry ({})",
+                    link_type, base_address, target_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::AppTokenToApp => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of an AppEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let app_entry = AppEntry::try_from( record )?;
+            let token_anchor : AnyLinkableHash = app_entry.app_token.anchor_path().path_entry_hash()?.into();
+
+            if base_address != token_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's app token",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::UiHashToUi => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a UiEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let ui_entry = UiEntry::try_from( record )?;
+            let hash_anchor : AnyLinkableHash = ui_entry.hash_anchor_path()?.path_entry_hash()?.into();
+
+            if base_address != hash_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's memory hash",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::IntegrityHashToApp => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of an AppEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let app_entry = AppEntry::try_from( record )?;
+            let integrity_anchor : AnyLinkableHash = app_entry.app_token.integrity_anchor_path().path_entry_hash()?.into();
+
+            if base_address != integrity_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's integrity hash",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::IntegrityHashToWebAppPackageVersion => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a WebAppPackageVersionEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let version_entry = WebAppPackageVersionEntry::try_from( record )?;
+            let integrity_anchor : AnyLinkableHash = version_entry.webapp_token.app_token.integrity_anchor_path().path_entry_hash()?.into();
+
+            if base_address != integrity_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's integrity hash",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::WebAppPackageVersionToReview => {
+            let review = ReviewEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != review.reviewer {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the reviewer",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != review.for_version.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the reviewed version",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::AgentToReview => {
+            let review = ReviewEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != review.reviewer {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the reviewer",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != review.reviewer.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the reviewer",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::WebAppPackageVersionToRebuildAttestation => {
+            let attestation = RebuildAttestationEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != attestation.attester {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the attester",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != attestation.for_version.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the attested version",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
         _ => invalid!(format!("Create link validation not implemented for link type: {:#?}", create.link_type )),
     }
 }
@@ -25,7 +25,9 @@ pub fn validation(
         EntryTypesUnit::Ui |
         EntryTypesUnit::WebApp |
         EntryTypesUnit::WebAppPackage |
-        EntryTypesUnit::WebAppPackageVersion => {
+        EntryTypesUnit::WebAppPackageVersion |
+        EntryTypesUnit::Review |
+        EntryTypesUnit::RebuildAttestation => {
             if delete.author != create.author {
                 invalid!(format!(
                     "Not authorized to delete entry created by author {}",
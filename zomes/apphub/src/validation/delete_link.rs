@@ -2,10 +2,17 @@ use crate::{
     hdi,
     hdi_extensions,
     LinkTypes,
+
+    WebAppPackageVersionEntry,
+    validation::{
+        check_authority,
+        check_webapp_package_authority,
+    },
 };
 
 use hdi::prelude::*;
 use hdi_extensions::{
+    AnyLinkableHashTransformer,
     // Macros
     valid, invalid,
 };
@@ -26,16 +33,54 @@ pub fn validation(
         None => invalid!(format!("No match for LinkTypes")),
     };
 
+    // The link author can always remove their own links
+    if create_link.author == delete.author {
+        valid!()
+    }
+
     match link_type {
-        _ => {
-            if create_link.author != delete.author {
-                invalid!(format!(
-                    "Not authorized to delete link created by author {}",
-                    create_link.author
-                ))
+        LinkTypes::WebAppPackage |
+        LinkTypes::NameIndexToWebAppPackage => {
+            if let ValidateCallbackResult::Invalid(msg) = check_webapp_package_authority(
+                &create_link.target_address.must_be_action_hash()?, &delete.author, &delete.prev_action,
+            )? {
+                invalid!(msg)
             }
 
             valid!()
         },
+        LinkTypes::WebAppPackageToWebAppPackageVersion => {
+            let webapp_package_version = WebAppPackageVersionEntry::try_from(
+                must_get_valid_record( create_link.target_address.must_be_action_hash()? )?
+            )?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_version.maintainer, &delete.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::App |
+        LinkTypes::Ui |
+        LinkTypes::WebApp |
+        LinkTypes::WebAppPackageVersion |
+        LinkTypes::AgentToApp |
+        LinkTypes::AgentToUi |
+        LinkTypes::AgentToWebApp |
+        LinkTypes::AgentToWebAppPackage |
+        LinkTypes::AgentToWebAppPackageVersion |
+        LinkTypes::DnaToApp |
+        LinkTypes::AppTokenToApp |
+        LinkTypes::UiHashToUi |
+        LinkTypes::IntegrityHashToApp |
+        LinkTypes::IntegrityHashToWebAppPackageVersion |
+        LinkTypes::WebAppPackageVersionToReview |
+        LinkTypes::AgentToReview |
+        LinkTypes::WebAppPackageVersionToRebuildAttestation => {
+            invalid!(format!(
+                "Not authorized to delete link created by author {}",
+                create_link.author
+            ))
+        },
     }
 }
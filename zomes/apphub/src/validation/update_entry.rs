@@ -2,9 +2,12 @@ use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
-    Authority,
     WebAppPackageEntry,
     WebAppPackageVersionEntry,
+    ReviewEntry,
+    validation::{
+        check_authority,
+    },
 };
 
 use hdi::prelude::*;
@@ -12,6 +15,10 @@ use hdi_extensions::{
     // Macros
     valid, invalid,
 };
+use devhub_types::{
+    check_maintainer_unchanged,
+    check_review,
+};
 
 
 pub fn validation(
@@ -31,47 +38,53 @@ pub fn validation(
             invalid!(format!("WebAppEntry are not intended to be updated"))
         },
         EntryTypes::WebAppPackage(webapp_package_entry) => {
-            // Check that the update is made by a maintainer
-            match webapp_package_entry.maintainer {
-                Authority::Agent(agent_id) => {
-                    if agent_id != update.author {
-                        invalid!(format!(
-                            "Not authorized to update entry; Only maintainer ({}) can make updates",
-                            agent_id,
-                        ))
-                    }
-                },
+            let previous_entry : WebAppPackageEntry = must_get_entry( original_entry_hash )?
+                .try_into()?;
+
+            //
+            // Check if new maintainer is valid
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &webapp_package_entry.maintainer,
+            )? {
+                invalid!(msg)
             }
 
-            if webapp_package_entry.deprecation.is_some() {
-                let previous_entry : WebAppPackageEntry = must_get_entry( original_entry_hash )?
-                    .try_into()?;
+            //
+            // Check if update author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_entry.maintainer, &update.author )? {
+                invalid!(msg)
+            }
 
-                if previous_entry.deprecation.is_some() {
-                    invalid!(format!(
-                        "Cannot update deprecated entity unless the deprecation is being reversed",
-                    ))
-                }
+            if webapp_package_entry.deprecation.is_some() && previous_entry.deprecation.is_some() {
+                invalid!(format!(
+                    "Cannot update deprecated entity unless the deprecation is being reversed",
+                ))
             }
 
             valid!()
         },
         EntryTypes::WebAppPackageVersion(webapp_package_version_entry) => {
-            // Check that the update is made by a maintainer
-            match webapp_package_version_entry.maintainer {
-                Authority::Agent(agent_id) => {
-                    if agent_id != update.author {
-                        invalid!(format!(
-                            "Not authorized to update entry; Only maintainer ({}) can make updates",
-                            agent_id,
-                        ))
-                    }
-                },
-            }
-
             let previous_entry : WebAppPackageVersionEntry = must_get_entry( original_entry_hash )?
                 .try_into()?;
 
+            //
+            // Check if new maintainer is valid
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &webapp_package_version_entry.maintainer,
+            )? {
+                invalid!(msg)
+            }
+
+            //
+            // Check if update author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &webapp_package_version_entry.maintainer, &update.author )? {
+                invalid!(msg)
+            }
+
             if webapp_package_version_entry.webapp != previous_entry.webapp {
                 invalid!(format!(
                     "WebApp reference cannot be changed; Create a new WebApp Package Version instead",
@@ -86,6 +99,29 @@ pub fn validation(
 
             valid!()
         },
+        EntryTypes::Review(entry) => {
+            let previous_entry : ReviewEntry = must_get_entry( original_entry_hash )?
+                .try_into()?;
+
+            if entry.reviewer != previous_entry.reviewer {
+                invalid!(format!(
+                    "The reviewer of a review cannot be changed: {} => {}",
+                    previous_entry.reviewer, entry.reviewer,
+                ))
+            }
+
+            if entry.for_version != previous_entry.for_version {
+                invalid!(format!(
+                    "The version of a review cannot be changed: {} => {}",
+                    previous_entry.for_version, entry.for_version,
+                ))
+            }
+
+            check_review( &entry, &update.author )
+        },
+        EntryTypes::RebuildAttestation(_) => {
+            invalid!(format!("RebuildAttestationEntry are not intended to be updated"))
+        },
         // _ => invalid!(format!("Update validation not implemented for entry type: {:#?}", update.entry_type )),
     }
 }
@@ -3,19 +3,24 @@ use crate::{
     hdk_extensions,
     hdi_extensions,
     MY_APPS_ANCHOR,
+    TypedLinkBase,
 };
 
 use hdk::prelude::*;
+use serde_bytes::ByteBuf;
 use hdk_extensions::{
     must_get,
 };
 use hdi_extensions::{
     ScopedTypeConnector,
+    guest_error,
 };
 use apphub::{
     EntryTypes,
     LinkTypes,
     AppEntry,
+    AppToken,
+    integrity_hash_anchor_path,
     hc_crud::{
         Entity,
         EntryModel,
@@ -25,15 +30,56 @@ use apphub::{
 use apphub_sdk::{
     LinkBase,
     AppEntryInput,
+    AppTokenInput,
     CreateAppInput,
     AppAsset,
+    AppVerificationReport,
+    DiffAppEntriesInput,
+    AppEntryDiff,
+    Page,
+    PaginatedInput,
+    verify_app_entry,
+    diff_app_tokens,
 };
 
 
+fn app_token_anchor(app_token: &AppToken) -> ExternResult<TypedLinkBase> {
+    let anchor_path = app_token.anchor_path().path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::AppTokenToApp ) )
+}
+
+
+fn integrity_hash_anchor(integrity_hash: &[u8]) -> ExternResult<TypedLinkBase> {
+    let anchor_path = integrity_hash_anchor_path( integrity_hash ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::IntegrityHashToApp ) )
+}
+
+
 fn create_app_entry_handler(entry: AppEntry) -> ExternResult<Entity<AppEntry>> {
+    // An identical App entry is reused instead of creating a duplicate
+    let addr = hash_entry( entry.clone() )?;
+    let existing = app_entries_by_token( &entry.app_token )?.into_iter()
+        .find( |entity| entity.address == addr );
+
+    if let Some(entity) = existing {
+        MY_APPS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+
+        return Ok( entity );
+    }
+
     let entity = create_entity( &entry )?;
 
     MY_APPS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+    app_token_anchor( &entry.app_token )?.create_link_if_not_exists( &entity.id, () )?;
+    integrity_hash_anchor( &entry.app_token.integrity_hash )?.create_link_if_not_exists( &entity.id, () )?;
+
+    // Reverse links so that dependents can be found from each DNA resource
+    for (name, hrl) in entry.resources.iter() {
+        LinkBase::new( hrl.target.clone(), LinkTypes::DnaToApp )
+            .create_link_if_not_exists( &entity.id, name.as_bytes().to_vec() )?;
+    }
 
     Ok( entity )
 }
@@ -47,7 +93,58 @@ fn create_app_entry(input: AppEntryInput) -> ExternResult<Entity<AppEntry>> {
 
 #[hdk_extern]
 pub fn create_app(input: CreateAppInput) -> ExternResult<Entity<AppEntry>> {
-    create_app_entry_handler( input.try_into()? )
+    let strict = input.strict;
+    let entry : AppEntry = input.try_into()?;
+
+    if strict {
+        let report = verify_app_entry( &entry )?;
+
+        if !report.valid {
+            return Err(guest_error!(format!(
+                "App failed verification: {:?}", report,
+            )));
+        }
+    }
+
+    create_app_entry_handler( entry )
+}
+
+
+/// Get the installable `.happ` bundle bytes for an App entry
+///
+/// Bundles whose role tokens do not match the DNA entries in dnahub are refused.
+#[hdk_extern]
+pub fn get_happ_bundle(addr: EntryHash) -> ExternResult<ByteBuf> {
+    let report = verify_app( addr.clone() )?;
+
+    if !report.valid {
+        return Err(guest_error!(format!(
+            "App entry ({}) failed verification: {:?}", addr, report,
+        )));
+    }
+
+    let app_asset : AppAsset = addr.try_into()?;
+
+    Ok( ByteBuf::from( app_asset.to_bundle_bytes()? ) )
+}
+
+
+/// Verify the App entry's role tokens against the DNA entries in dnahub
+#[hdk_extern]
+pub fn verify_app(addr: EntryHash) -> ExternResult<AppVerificationReport> {
+    let app_entry : AppEntry = must_get( &addr )?.try_into()?;
+
+    verify_app_entry( &app_entry )
+}
+
+
+/// Compare two App entries role by role to see if they are a coordinator update
+#[hdk_extern]
+pub fn diff_app_entries(input: DiffAppEntriesInput) -> ExternResult<AppEntryDiff> {
+    let from : AppEntry = must_get( &input.from )?.try_into()?;
+    let to : AppEntry = must_get( &input.to )?.try_into()?;
+
+    Ok( diff_app_tokens( &from.app_token, &to.app_token ) )
 }
 
 
@@ -70,6 +167,39 @@ pub fn get_app_entry(addr: AnyDhtHash) -> ExternResult<Entity<AppEntry>> {
 }
 
 
+fn app_entries_by_token(app_token: &AppToken) -> ExternResult<Vec<Entity<AppEntry>>> {
+    let apps = app_token_anchor( app_token )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_app_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( apps )
+}
+
+
+/// Get the App entries that were created with the given App token
+#[hdk_extern]
+pub fn get_app_entries_by_token(input: AppTokenInput) -> ExternResult<Vec<Entity<AppEntry>>> {
+    app_entries_by_token( &AppToken::from( input ) )
+}
+
+
+/// Get the App entries that share an integrity hash (ie. are network compatible)
+#[hdk_extern]
+pub fn get_apps_with_integrity_hash(integrity_hash: ByteBuf) -> ExternResult<Vec<Entity<AppEntry>>> {
+    let apps = integrity_hash_anchor( &integrity_hash )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_app_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( apps )
+}
+
+
 #[hdk_extern]
 fn get_app_asset(addr: EntryHash) -> ExternResult<AppAsset> {
     Ok( addr.try_into()? )
@@ -97,7 +227,62 @@ pub fn get_app_entries_for_agent(
 }
 
 
+#[hdk_extern]
+pub fn get_app_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<AppEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToApp );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_entry_hash()?;
+                get_app_entry( addr.into() ).ok()
+            })
+    )
+}
+
+
+/// Get the App entries that reference the given DNA resource
+///
+/// The input is an `HRL.target` address, so the results of `get_dependents` in another hub can
+/// be chained into this one using `call_cell`.
+#[hdk_extern]
+fn get_dependents(addr: AnyDhtHash) -> ExternResult<Vec<Entity<AppEntry>>> {
+    let base = LinkBase::new( addr, LinkTypes::DnaToApp );
+
+    let mut seen = std::collections::BTreeSet::new();
+    let dependents = base.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            match seen.insert( id.clone() ) {
+                true => get_app_entry( id.into() ).ok(),
+                false => None,
+            }
+        })
+        .collect();
+
+    Ok( dependents )
+}
+
+
 #[hdk_extern]
 fn delete_app(addr: ActionHash) -> ExternResult<ActionHash> {
-    Ok( delete_entity::<AppEntry,EntryTypes>( &addr )? )
+    let app_entry = get_app_entry( addr.clone().into() )?.content;
+
+    for hrl in app_entry.resources.values() {
+        LinkBase::new( hrl.target.clone(), LinkTypes::DnaToApp )
+            .delete_all_my_links_to_target( &addr, None )?;
+    }
+
+    let delete_addr = delete_entity::<AppEntry,EntryTypes>( &addr )?;
+
+    app_token_anchor( &app_entry.app_token )?.delete_all_my_links_to_target( &addr, None )?;
+    integrity_hash_anchor( &app_entry.app_token.integrity_hash )?.delete_all_my_links_to_target( &addr, None )?;
+
+    Ok( delete_addr )
 }
@@ -0,0 +1,21 @@
+use crate::{
+    hdk,
+};
+
+use hdk::prelude::*;
+use apphub_sdk::{
+    IngestedBundle,
+    get_memory,
+};
+
+
+/// Unpack a `.dna`, `.happ` or `.webhapp` bundle stored in mere_memory
+///
+/// Creates (or reuses) the zome, DNA, App, UI and WebApp entries across the 3 roles and returns
+/// the resulting entity tree.
+#[hdk_extern]
+fn ingest_bundle(mere_memory_addr: EntryHash) -> ExternResult<IngestedBundle> {
+    let (memory, bytes) = get_memory( &mere_memory_addr )?;
+
+    apphub_sdk::ingest_bundle( &bytes, memory.uncompressed_size.unwrap_or( memory.memory_size ) )
+}
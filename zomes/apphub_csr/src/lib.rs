@@ -1,9 +1,12 @@
 mod app_handlers;
+mod bundle_handlers;
 mod ui_handlers;
 mod webapp_handlers;
 mod webapp_package_handlers;
 mod webapp_package_version_handlers;
 mod webapp_package_base;
+mod review_handlers;
+mod rebuild_attestation_handlers;
 
 
 pub use apphub::hdi;
@@ -24,10 +27,15 @@ use apphub::{
 use apphub_sdk::{
     PathInput,
     LinkBase,
+    NameIndex,
+    devhub_types::{
+        DevHubProperties,
+    },
 };
 
 
 pub type TypedLinkBase = LinkBase<LinkTypes>;
+pub type TypedNameIndex = NameIndex<LinkTypes>;
 
 lazy_static! {
     pub static ref AGENT_ID : AgentPubKey = agent_id().expect("Unable to obtain current Agent context");
@@ -41,6 +49,8 @@ lazy_static! {
     pub static ref MY_WEBAPPS_ANCHOR			: TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToWebApp );
     pub static ref MY_WEBAPP_PACKS_ANCHOR		: TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToWebAppPackage );
     pub static ref MY_WEBAPP_PACK_VERSIONS_ANCHOR	: TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToWebAppPackageVersion );
+
+    pub static ref WEBAPP_PACKS_NAME_INDEX		: TypedNameIndex = NameIndex::new( LinkTypes::NameIndexToWebAppPackage );
 }
 
 
@@ -50,6 +60,15 @@ fn init(_: ()) -> ExternResult<InitCallbackResult> {
     let zome_name = zome_settings.name;
     debug!("'{}' init", zome_name );
 
+    // Every upload would fail validation with malformed DNA properties
+    let properties = match DevHubProperties::get() {
+        Ok(properties) => properties,
+        Err(err) => return Ok(InitCallbackResult::Fail(format!(
+            "Invalid DNA properties: {:?}", err,
+        ))),
+    };
+    debug!("Storage quota: {:?}", properties.storage_quota );
+
     let main_functions : Vec<(&str, &str)> = zome_settings.extern_fns.iter()
         .filter_map(|fn_name| match fn_name.as_ref().starts_with("get_") {
             true => Some(( zome_name.0.as_ref(), fn_name.0.as_str() )),
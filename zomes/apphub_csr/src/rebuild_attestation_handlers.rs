@@ -0,0 +1,69 @@
+use crate::{
+    hdk,
+};
+
+use hdk::prelude::*;
+use apphub::{
+    EntryTypes,
+    LinkTypes,
+
+    WebAppEntry,
+    WebAppPackageVersionEntry,
+    UiEntry,
+    RebuildAttestationEntry,
+    hc_crud::{
+        Entity, EntityId,
+    },
+};
+use apphub_sdk::{
+    RebuildAttestationLinks,
+    SubmitRebuildAttestationInput,
+};
+
+
+fn attestations() -> RebuildAttestationLinks<EntryTypes, LinkTypes> {
+    RebuildAttestationLinks::new( LinkTypes::WebAppPackageVersionToRebuildAttestation )
+}
+
+
+#[hdk_extern]
+fn submit_rebuild_attestation(input: SubmitRebuildAttestationInput) ->
+    ExternResult<Entity<RebuildAttestationEntry>>
+{
+    attestations().submit_rebuild_attestation( input )
+}
+
+
+#[hdk_extern]
+fn get_rebuild_attestation(addr: EntityId) -> ExternResult<Entity<RebuildAttestationEntry>> {
+    attestations().get_rebuild_attestation( &addr )
+}
+
+
+#[hdk_extern]
+fn get_rebuild_attestations_for_version(version_id: EntityId) ->
+    ExternResult<Vec<Entity<RebuildAttestationEntry>>>
+{
+    attestations().get_rebuild_attestations_for_version( &version_id )
+}
+
+
+/// Count the attestations whose hash matches the memory hash of the version's UI
+///
+/// The UI is the part of a WebApp that is built outside of the hApp bundle.
+pub fn count_matching_rebuild_attestations(
+    version_id: &EntityId,
+    version: &WebAppPackageVersionEntry,
+) -> ExternResult<u64> {
+    let webapp_entry : WebAppEntry = must_get_entry( version.webapp.clone() )?.content.try_into()?;
+    let ui_entry : UiEntry = must_get_entry( webapp_entry.ui_entry_addr()? )?.content.try_into()?;
+    let ui_hash = ui_entry.memory_hash()?;
+
+    attestations().count_matching_rebuild_attestations( version_id, &ui_hash )
+}
+
+
+#[hdk_extern]
+fn delete_rebuild_attestation(id: EntityId) -> ExternResult<ActionHash> {
+    attestations().delete_rebuild_attestation( &id )
+}
@@ -0,0 +1,70 @@
+use crate::{
+    hdk,
+};
+
+use hdk::prelude::*;
+use apphub::{
+    EntryTypes,
+    LinkTypes,
+
+    ReviewEntry,
+    ReviewSummary,
+    hc_crud::{
+        Entity, EntityId,
+        UpdateEntityInput,
+    },
+};
+use apphub_sdk::{
+    ReviewLinks,
+    CreateReviewInput,
+    UpdateReviewInput,
+};
+
+
+fn reviews() -> ReviewLinks<EntryTypes, LinkTypes> {
+    ReviewLinks::new( LinkTypes::WebAppPackageVersionToReview, LinkTypes::AgentToReview )
+}
+
+
+#[hdk_extern]
+fn create_review(input: CreateReviewInput) -> ExternResult<Entity<ReviewEntry>> {
+    reviews().create_review( input )
+}
+
+
+#[hdk_extern]
+fn get_review(addr: EntityId) -> ExternResult<Entity<ReviewEntry>> {
+    reviews().get_review( &addr )
+}
+
+
+#[hdk_extern]
+fn get_reviews_for_version(version_id: EntityId) -> ExternResult<Vec<Entity<ReviewEntry>>> {
+    reviews().get_reviews_for_version( &version_id )
+}
+
+
+#[hdk_extern]
+fn get_reviews_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
+    ExternResult<Vec<Entity<ReviewEntry>>>
+{
+    reviews().get_reviews_for_agent( maybe_agent_id )
+}
+
+
+#[hdk_extern]
+fn get_review_summary(version_id: EntityId) -> ExternResult<ReviewSummary> {
+    reviews().get_review_summary( &version_id )
+}
+
+
+#[hdk_extern]
+fn update_review(input: UpdateEntityInput<UpdateReviewInput>) -> ExternResult<Entity<ReviewEntry>> {
+    reviews().update_review( input )
+}
+
+
+#[hdk_extern]
+fn delete_review(id: EntityId) -> ExternResult<ActionHash> {
+    reviews().delete_review( &id )
+}
@@ -3,6 +3,7 @@ use crate::{
     hdk_extensions,
     hdi_extensions,
     MY_UIS_ANCHOR,
+    TypedLinkBase,
 };
 
 use hdk::prelude::*;
@@ -14,8 +15,10 @@ use hdi_extensions::{
 };
 use apphub::{
     EntryTypes,
+    EntryTypesUnit,
     LinkTypes,
     UiEntry,
+    ui_hash_anchor_path,
     hc_crud::{
         Entity,
         EntryModel,
@@ -25,13 +28,36 @@ use apphub::{
 use apphub_sdk::{
     LinkBase,
     UiAsset,
+    CreateUiEntryInput,
+    StorageUsage,
+    Page,
+    PaginatedInput,
 };
 
 
+fn ui_hash_anchor(hash: &str) -> ExternResult<TypedLinkBase> {
+    let anchor_path = ui_hash_anchor_path( hash ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::UiHashToUi ) )
+}
+
+
 fn create_ui_entry_handler(entry: UiEntry) -> ExternResult<Entity<UiEntry>> {
+    // Identical UI content is reused instead of creating a duplicate entry
+    let hash = entry.memory_hash()?;
+    let existing = get_ui_entries_by_hash( hash.clone() )?.into_iter()
+        .find( |entity| entity.content.file_size == entry.file_size );
+
+    if let Some(entity) = existing {
+        MY_UIS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+
+        return Ok( entity );
+    }
+
     let entity = create_entity( &entry )?;
 
     MY_UIS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+    ui_hash_anchor( &hash )?.create_link_if_not_exists( &entity.id, () )?;
 
     Ok( entity )
 }
@@ -43,11 +69,6 @@ fn create_ui_entry(input: UiEntry) -> ExternResult<Entity<UiEntry>> {
 }
 
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct CreateUiEntryInput {
-    pub mere_memory_addr: EntryHash,
-}
-
 #[hdk_extern]
 pub fn create_ui(input: CreateUiEntryInput) -> ExternResult<Entity<UiEntry>> {
     let entry = UiEntry::new( input.mere_memory_addr )?;
@@ -75,6 +96,20 @@ pub fn get_ui_entry(addr: AnyDhtHash) -> ExternResult<Entity<UiEntry>> {
 }
 
 
+/// Get the UI entries for a memory hash (`MemoryEntry.hash`)
+#[hdk_extern]
+pub fn get_ui_entries_by_hash(hash: String) -> ExternResult<Vec<Entity<UiEntry>>> {
+    let uis = ui_hash_anchor( &hash )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_ui_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( uis )
+}
+
+
 #[hdk_extern]
 fn get_ui_asset(addr: EntryHash) -> ExternResult<UiAsset> {
     Ok( addr.try_into()? )
@@ -102,7 +137,49 @@ pub fn get_ui_entries_for_agent(
 }
 
 
+#[hdk_extern]
+pub fn get_ui_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<UiEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToUi );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_entry_hash()?;
+                get_ui_entry( addr.into() ).ok()
+            })
+    )
+}
+
+
+/// Get the total size of the UIs created by an agent and the storage quota of this DNA
+#[hdk_extern]
+pub fn get_agent_storage_usage(maybe_agent_id: Option<AgentPubKey>) -> ExternResult<StorageUsage> {
+    let agent_id = match maybe_agent_id {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+
+    apphub_sdk::get_agent_storage_usage(
+        agent_id,
+        EntryTypesUnit::Ui.try_into()?,
+        |entry| Ok( UiEntry::try_from( entry )?.file_size ),
+    )
+}
+
+
 #[hdk_extern]
 fn delete_ui(addr: ActionHash) -> ExternResult<ActionHash> {
-    Ok( delete_entity::<UiEntry,EntryTypes>( &addr )? )
+    let ui_entry = get_ui_entry( addr.clone().into() )?.content;
+    let hash = ui_entry.memory_hash()?;
+    let delete_addr = delete_entity::<UiEntry,EntryTypes>( &addr )?;
+
+    ui_hash_anchor( &hash )?.delete_all_my_links_to_target( &addr, None )?;
+
+    Ok( delete_addr )
 }
@@ -6,11 +6,13 @@ use crate::{
 };
 
 use hdk::prelude::*;
+use serde_bytes::ByteBuf;
 use hdk_extensions::{
     must_get,
 };
 use hdi_extensions::{
     ScopedTypeConnector,
+    guest_error,
 };
 use apphub::{
     EntryTypes,
@@ -27,6 +29,10 @@ use apphub_sdk::{
     WebAppEntryInput,
     CreateWebAppInput,
     WebAppAsset,
+    WebAppVerificationReport,
+    Page,
+    PaginatedInput,
+    verify_webapp_entry,
 };
 
 
@@ -47,7 +53,48 @@ fn create_webapp_entry(input: WebAppEntryInput) -> ExternResult<Entity<WebAppEnt
 
 #[hdk_extern]
 pub fn create_webapp(input: CreateWebAppInput) -> ExternResult<Entity<WebAppEntry>> {
-    create_webapp_entry_handler( input.try_into()? )
+    let strict = input.strict;
+    let entry : WebAppEntry = input.try_into()?;
+
+    if strict {
+        let report = verify_webapp_entry( &entry )?;
+
+        if !report.valid {
+            return Err(guest_error!(format!(
+                "WebApp failed verification: {:?}", report,
+            )));
+        }
+    }
+
+    create_webapp_entry_handler( entry )
+}
+
+
+/// Get the installable `.webhapp` bundle bytes for a WebApp entry
+///
+/// Bundles whose WebApp, App or UI tokens cannot be verified are refused.
+#[hdk_extern]
+pub fn get_webhapp_bundle(addr: EntryHash) -> ExternResult<ByteBuf> {
+    let report = verify_webapp( addr.clone() )?;
+
+    if !report.valid {
+        return Err(guest_error!(format!(
+            "WebApp entry ({}) failed verification: {:?}", addr, report,
+        )));
+    }
+
+    let webapp_asset : WebAppAsset = addr.try_into()?;
+
+    Ok( ByteBuf::from( webapp_asset.to_bundle_bytes()? ) )
+}
+
+
+/// Verify the WebApp entry's token and its App and UI entries
+#[hdk_extern]
+pub fn verify_webapp(addr: EntryHash) -> ExternResult<WebAppVerificationReport> {
+    let webapp_entry : WebAppEntry = must_get( &addr )?.try_into()?;
+
+    verify_webapp_entry( &webapp_entry )
 }
 
 
@@ -96,6 +143,26 @@ pub fn get_webapp_entries_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
 }
 
 
+#[hdk_extern]
+pub fn get_webapp_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<WebAppEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToWebApp );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_entry_hash()?;
+                get_webapp_entry( addr.into() ).ok()
+            })
+    )
+}
+
+
 #[hdk_extern]
 fn delete_webapp(addr: ActionHash) -> ExternResult<ActionHash> {
     Ok( delete_entity::<WebAppEntry,EntryTypes>( &addr )? )
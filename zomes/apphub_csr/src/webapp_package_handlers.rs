@@ -5,6 +5,7 @@ use crate::{
     WebAppPackageBase,
     MY_WEBAPP_PACKS_ANCHOR,
     ALL_WEBAPP_PACKS_ANCHOR,
+    WEBAPP_PACKS_NAME_INDEX,
 };
 
 use std::collections::BTreeMap;
@@ -20,6 +21,7 @@ use apphub::{
     EntryTypes,
     LinkTypes,
     WebAppPackageEntry,
+    WebAppPackageVersionEntry,
     Authority,
     MemoryAddr,
     DeprecationNotice,
@@ -32,10 +34,17 @@ use apphub::{
 };
 use apphub_sdk::{
     LinkBase,
+    normalize_name,
     EntityPointerMap,
     WebAppPackageEntryInput,
     CreateWebAppPackageInput,
     WebAppPackageVersionMap,
+    Page,
+    PageQuery,
+    PaginatedInput,
+};
+use coop_content_sdk::{
+    get_group_content_latest,
 };
 
 
@@ -47,6 +56,8 @@ fn create_webapp_package_entry_handler(entry: WebAppPackageEntry) ->
     MY_WEBAPP_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, () )?;
     ALL_WEBAPP_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, () )?;
 
+    WEBAPP_PACKS_NAME_INDEX.add( &entry.title, &entity.id )?;
+
     Ok( entity )
 }
 
@@ -87,7 +98,33 @@ pub fn get_webapp_package_entry(addr: AnyDhtHash) -> ExternResult<Entity<WebAppP
 
 #[hdk_extern]
 pub fn get_webapp_package(addr: EntityId) -> ExternResult<Entity<WebAppPackageEntry>> {
-    Ok( get_entity( &addr )? )
+    let addr = trace_origin_root( &addr )?.0;
+    let record = must_get( &addr )?;
+    let webapp_package_entry = WebAppPackageEntry::try_from_record( &record )?;
+
+    Ok(match webapp_package_entry.maintainer {
+        Authority::Agent(_) => {
+            get_entity( &addr )?
+        },
+        Authority::Group(group_id, _) => {
+            let latest_addr = get_group_content_latest!({
+                group_id: group_id,
+                content_id: addr.clone().into(),
+            })?;
+            let record = must_get( &latest_addr )?;
+            let content = WebAppPackageEntry::try_from_record( &record )?;
+            let id = record.action_address().to_owned();
+            let hash = hash_entry( content.clone() )?;
+
+            Entity {
+                id: addr,
+                action: id,
+	        address: hash,
+	        ctype: content.get_type(),
+	        content: content,
+            }
+        },
+    })
 }
 
 
@@ -162,6 +199,16 @@ pub fn get_webapp_package_versions(webapp_package_id: EntityId) ->
 }
 
 
+#[hdk_extern]
+pub fn get_webapp_package_versions_paginated(input: PaginatedInput<EntityId>) ->
+    ExternResult<Page<(String, Entity<WebAppPackageVersionEntry>)>>
+{
+    let base = WebAppPackageBase::new( &input.input );
+
+    base.versions_page( &input.page )
+}
+
+
 #[hdk_extern]
 pub fn get_webapp_package_entries_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
     ExternResult<Vec<Entity<WebAppPackageEntry>>>
@@ -182,6 +229,26 @@ pub fn get_webapp_package_entries_for_agent(maybe_agent_id: Option<AgentPubKey>)
 }
 
 
+#[hdk_extern]
+pub fn get_webapp_package_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<WebAppPackageEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToWebAppPackage );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_webapp_package( addr ).ok()
+            })
+    )
+}
+
+
 #[hdk_extern]
 pub fn get_all_webapp_packages(_: ()) -> ExternResult<Vec<Entity<WebAppPackageEntry>>> {
     let webapps = ALL_WEBAPP_PACKS_ANCHOR.get_links( None )?.into_iter()
@@ -195,6 +262,47 @@ pub fn get_all_webapp_packages(_: ()) -> ExternResult<Vec<Entity<WebAppPackageEn
 }
 
 
+#[hdk_extern]
+pub fn get_all_webapp_packages_paginated(input: PageQuery) ->
+    ExternResult<Page<Entity<WebAppPackageEntry>>>
+{
+    Ok(
+        ALL_WEBAPP_PACKS_ANCHOR.get_links_page( None, &input )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_webapp_package( addr ).ok()
+            })
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_webapp_packages_by_name_prefix(prefix: String) ->
+    ExternResult<Vec<Entity<WebAppPackageEntry>>>
+{
+    let prefix = normalize_name( &prefix );
+
+    Ok(
+        WEBAPP_PACKS_NAME_INDEX.search( &prefix )?.into_iter()
+            .filter_map( |link| get_webapp_package( link.target.into_action_hash()? ).ok() )
+            .filter( |entity| normalize_name( &entity.content.title ).starts_with( &prefix ) )
+            .collect()
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_webapp_packages_by_name_fuzzy(query: String) ->
+    ExternResult<Vec<Entity<WebAppPackageEntry>>>
+{
+    Ok(
+        WEBAPP_PACKS_NAME_INDEX.fuzzy_search( &query )?.into_iter()
+            .filter_map( |link| get_webapp_package( link.target.into_action_hash()? ).ok() )
+            .collect()
+    )
+}
+
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpdateWebAppPackageInput {
     pub title: Option<String>,
@@ -212,6 +320,7 @@ pub fn update_webapp_package(input: UpdateEntityInput<UpdateWebAppPackageInput>)
     ExternResult<Entity<WebAppPackageEntry>>
 {
     let changes = input.properties;
+    let previous_title = get_webapp_package_entry( input.base.clone().into() )?.content.title;
     let entity = update_entity( &input.base, |package: WebAppPackageEntry, _| {
         let entry = WebAppPackageEntry {
             title: changes.title
@@ -235,21 +344,50 @@ pub fn update_webapp_package(input: UpdateEntityInput<UpdateWebAppPackageInput>)
 	Ok( entry )
     })?;
 
+    if normalize_name( &previous_title ) != normalize_name( &entity.content.title ) {
+        match entity.content.maintainer {
+            // Any group contributor may have indexed the previous title
+            Authority::Group(..) => WEBAPP_PACKS_NAME_INDEX.remove_all( &previous_title, &entity.id )?,
+            Authority::Agent(_) => WEBAPP_PACKS_NAME_INDEX.remove( &previous_title, &entity.id )?,
+        };
+        WEBAPP_PACKS_NAME_INDEX.add( &entity.content.title, &entity.id )?;
+    }
+
     Ok( entity )
 }
 
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeprecateWebAppPackageInput {
+    pub message: String,
+    #[serde(default)]
+    pub recommended_alternatives: Vec<ActionHash>,
+    pub maintainer: Option<Authority>,
+}
+
 #[hdk_extern]
-pub fn deprecate_webapp_package(input: UpdateEntityInput<DeprecationNotice>) ->
+pub fn deprecate_webapp_package(input: UpdateEntityInput<DeprecateWebAppPackageInput>) ->
     ExternResult<Entity<WebAppPackageEntry>>
 {
+    let changes = input.properties;
     let entity = update_entity( &input.base, |mut package: WebAppPackageEntry, _| {
-        package.deprecation = Some(input.properties.clone());
+        package.deprecation = Some(DeprecationNotice {
+            message: changes.message.clone(),
+            recommended_alternatives: changes.recommended_alternatives.clone(),
+        });
+
+        if let Some(maintainer) = changes.maintainer.clone() {
+            package.maintainer = maintainer;
+        }
 
 	Ok( package )
     })?;
 
-    ALL_WEBAPP_PACKS_ANCHOR.delete_all_my_links_to_target( &entity.id, None )?;
+    match entity.content.maintainer {
+        // Any group contributor may have created the link
+        Authority::Group(..) => ALL_WEBAPP_PACKS_ANCHOR.delete_all_links_to_target( &entity.id, None )?,
+        Authority::Agent(_) => ALL_WEBAPP_PACKS_ANCHOR.delete_all_my_links_to_target( &entity.id, None )?,
+    };
 
     Ok( entity )
 }
@@ -3,7 +3,11 @@ use crate::{
     hdk_extensions,
     hdi_extensions,
     webapp_package_handlers,
+    rebuild_attestation_handlers::{
+        count_matching_rebuild_attestations,
+    },
     MY_WEBAPP_PACK_VERSIONS_ANCHOR,
+    TypedLinkBase,
 };
 
 use std::collections::BTreeMap;
@@ -12,6 +16,7 @@ use hdk_extensions::{
     must_get,
 };
 use hdi_extensions::{
+    trace_origin_root,
     ScopedTypeConnector,
 };
 use apphub::{
@@ -19,9 +24,12 @@ use apphub::{
     LinkTypes,
     WebAppPackageVersionEntry,
     Authority,
+    BuildProvenance,
+    integrity_hash_anchor_path,
     hc_crud::{
         Entity, EntityId,
         UpdateEntityInput,
+        EntryModel,
         create_entity, get_entity, update_entity, delete_entity,
     },
 };
@@ -30,6 +38,15 @@ use apphub_sdk::{
     MoveLinkInput,
     WebAppPackageVersionEntryInput,
     CreateWebAppPackageVersionInput,
+    DiffWebAppPackageVersionsInput,
+    WebAppDiff,
+    AttestedVersion,
+    diff_webapp_tokens,
+    Page,
+    PaginatedInput,
+};
+use coop_content_sdk::{
+    get_group_content_latest,
 };
 use webapp_package_handlers::{
     DeleteLinkWebAppPackageVersionInput,
@@ -39,12 +56,21 @@ use webapp_package_handlers::{
 };
 
 
+fn integrity_hash_anchor(integrity_hash: &[u8]) -> ExternResult<TypedLinkBase> {
+    let anchor_path = integrity_hash_anchor_path( integrity_hash ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::IntegrityHashToWebAppPackageVersion ) )
+}
+
+
 fn create_webapp_package_version_entry_handler(entry: WebAppPackageVersionEntry) ->
     ExternResult<Entity<WebAppPackageVersionEntry>>
 {
     let entity = create_entity( &entry )?;
 
     MY_WEBAPP_PACK_VERSIONS_ANCHOR.create_link_if_not_exists( &entity.id, () )?;
+    integrity_hash_anchor( &entry.webapp_token.app_token.integrity_hash )?
+        .create_link_if_not_exists( &entity.id, () )?;
 
     Ok( entity )
 }
@@ -84,11 +110,55 @@ pub fn get_webapp_package_version_entry(addr: ActionHash) ->
 }
 
 
+/// Get the latest state of a version, following group content updates
+pub fn get_webapp_package_version_entity(addr: ActionHash) ->
+    ExternResult<Entity<WebAppPackageVersionEntry>>
+{
+    let addr = trace_origin_root( &addr )?.0;
+    let record = must_get( &addr )?;
+    let webapp_package_version_entry = WebAppPackageVersionEntry::try_from_record( &record )?;
+
+    Ok(match webapp_package_version_entry.maintainer {
+        Authority::Agent(_) => {
+            get_entity( &addr )?
+        },
+        Authority::Group(group_id, _) => {
+            let latest_addr = get_group_content_latest!({
+                group_id: group_id,
+                content_id: addr.clone().into(),
+            })?;
+            let record = must_get( &latest_addr )?;
+            let content = WebAppPackageVersionEntry::try_from_record( &record )?;
+            let id = record.action_address().to_owned();
+            let hash = hash_entry( content.clone() )?;
+
+            Entity {
+                id: addr,
+                action: id,
+	        address: hash,
+	        ctype: content.get_type(),
+	        content,
+            }
+        },
+    })
+}
+
+
 #[hdk_extern]
 pub fn get_webapp_package_version(addr: ActionHash) ->
-    ExternResult<Entity<WebAppPackageVersionEntry>>
+    ExternResult<AttestedVersion<WebAppPackageVersionEntry>>
 {
-    Ok( get_entity( &addr )? )
+    let version = get_webapp_package_version_entity( addr )?;
+    let matching_rebuild_attestations = count_matching_rebuild_attestations(
+        &version.id, &version.content,
+    )?;
+
+    Ok(
+        AttestedVersion {
+            version,
+            matching_rebuild_attestations,
+        }
+    )
 }
 
 
@@ -104,7 +174,7 @@ pub fn get_webapp_package_version_entries_for_agent(maybe_agent_id: Option<Agent
     let versions = agent_anchor.get_links( None )?.into_iter()
         .filter_map(|link| {
             let addr = link.target.into_action_hash()?;
-            get_webapp_package_version( addr ).ok()
+            get_webapp_package_version_entity( addr ).ok()
         })
         .collect();
 
@@ -112,12 +182,71 @@ pub fn get_webapp_package_version_entries_for_agent(maybe_agent_id: Option<Agent
 }
 
 
+#[hdk_extern]
+pub fn get_webapp_package_version_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<WebAppPackageVersionEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToWebAppPackageVersion );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_webapp_package_version_entity( addr ).ok()
+            })
+    )
+}
+
+
+/// Get the other versions whose hApp has the same integrity hash as the given version
+///
+/// These versions can join the same networks, so switching between them only swaps coordinators.
+#[hdk_extern]
+pub fn get_compatible_app_versions(version_id: EntityId) ->
+    ExternResult<Vec<Entity<WebAppPackageVersionEntry>>>
+{
+    let version = get_webapp_package_version_entity( version_id.clone() )?;
+    let integrity_hash = version.content.webapp_token.app_token.integrity_hash;
+
+    let versions = integrity_hash_anchor( &integrity_hash )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let addr = link.target.into_action_hash()?;
+
+            if addr == version_id {
+                return None;
+            }
+
+            get_webapp_package_version_entity( addr ).ok()
+        })
+        .collect();
+
+    Ok( versions )
+}
+
+
+/// Compare the WebApp tokens of two versions (roles and UI)
+#[hdk_extern]
+pub fn diff_webapp_package_versions(input: DiffWebAppPackageVersionsInput) ->
+    ExternResult<WebAppDiff>
+{
+    let from = get_webapp_package_version_entity( input.from )?.content;
+    let to = get_webapp_package_version_entity( input.to )?.content;
+
+    Ok( diff_webapp_tokens( &from.webapp_token, &to.webapp_token ) )
+}
+
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UpdateWebAppPackageVersionInput {
     pub for_package: Option<EntityId>,
     pub changelog: Option<String>,
     pub maintainer: Option<Authority>,
     pub source_code_revision_uri: Option<String>,
+    pub build_provenance: Option<BuildProvenance>,
     pub metadata: Option<BTreeMap<String, rmpv::Value>>,
 }
 
@@ -138,6 +267,8 @@ pub fn update_webapp_package_version(input: UpdateEntityInput<UpdateWebAppPackag
                 .unwrap_or( version.maintainer ).into(),
             source_code_revision_uri: changes.source_code_revision_uri
                 .or( version.source_code_revision_uri ),
+            build_provenance: changes.build_provenance
+                .or( version.build_provenance ),
             metadata: changes.metadata
                 .unwrap_or( version.metadata ),
         };
@@ -166,7 +297,7 @@ pub fn move_webapp_package_version(input: MoveWebAppPackageVersionInput) ->
     })?;
     debug!("Deleted links: {:?}", deleted_links.iter().map( |hash| format!("{}", hash) ).collect::<Vec<String>>() );
 
-    let version = get_webapp_package_version( input.webapp_package_version_id.clone() )?;
+    let version = get_webapp_package_version_entity( input.webapp_package_version_id.clone() )?;
     let entity = update_webapp_package_version(UpdateEntityInput {
 	base: version.action.clone(),
 	properties: UpdateWebAppPackageVersionInput {
@@ -174,6 +305,7 @@ pub fn move_webapp_package_version(input: MoveWebAppPackageVersionInput) ->
             changelog: None,
             maintainer: None,
             source_code_revision_uri: None,
+            build_provenance: None,
             metadata: None,
         },
     })?;
@@ -190,5 +322,11 @@ pub fn move_webapp_package_version(input: MoveWebAppPackageVersionInput) ->
 
 #[hdk_extern]
 fn delete_webapp_package_version(addr: ActionHash) -> ExternResult<ActionHash> {
-    Ok( delete_entity::<WebAppPackageVersionEntry,EntryTypes>( &addr )? )
+    let version = get_webapp_package_version_entry( addr.clone() )?;
+    let delete_addr = delete_entity::<WebAppPackageVersionEntry,EntryTypes>( &addr )?;
+
+    integrity_hash_anchor( &version.webapp_token.app_token.integrity_hash )?
+        .delete_all_my_links_to_target( &addr, None )?;
+
+    Ok( delete_addr )
 }
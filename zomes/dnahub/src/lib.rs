@@ -25,15 +25,29 @@ use hc_crud::{
 pub enum EntryTypes {
     #[entry_type]
     Dna(DnaEntry),
+    #[entry_type]
+    DnaPackage(DnaPackageEntry),
+    #[entry_type]
+    DnaPackageVersion(DnaPackageVersionEntry),
 }
 
 scoped_type_connector!(
     EntryTypesUnit::Dna,
     EntryTypes::Dna( DnaEntry )
 );
+scoped_type_connector!(
+    EntryTypesUnit::DnaPackage,
+    EntryTypes::DnaPackage( DnaPackageEntry )
+);
+scoped_type_connector!(
+    EntryTypesUnit::DnaPackageVersion,
+    EntryTypes::DnaPackageVersion( DnaPackageVersionEntry )
+);
 
 // Entity implementations
 entry_model!( EntryTypes::Dna( DnaEntry ) );
+entry_model!( EntryTypes::DnaPackage( DnaPackageEntry ) );
+entry_model!( EntryTypes::DnaPackageVersion( DnaPackageVersionEntry ) );
 
 
 
@@ -41,6 +55,20 @@ entry_model!( EntryTypes::Dna( DnaEntry ) );
 #[hdk_link_types]
 pub enum LinkTypes {
     Dna,
+    DnaPackage,
+
+    AgentToDnaPackage,
+
+    NameToDnaPackage,
+    NameIndexToDnaPackage,
+
+    /// Tagged with the version; a tag is unique per author only, so the oldest claim wins
+    DnaPackageToDnaPackageVersion,
+
+    ZomeToDna,
+
+    DnaTokenToDna,
+    IntegrityHashToDna,
 }
 
 impl TryFrom<String> for LinkTypes {
@@ -50,6 +78,20 @@ impl TryFrom<String> for LinkTypes {
         Ok(
             match name.as_str() {
                 "Dna" => LinkTypes::Dna,
+                "DnaPackage" => LinkTypes::DnaPackage,
+
+                "AgentToDnaPackage" => LinkTypes::AgentToDnaPackage,
+
+                "NameToDnaPackage" => LinkTypes::NameToDnaPackage,
+                "NameIndexToDnaPackage" => LinkTypes::NameIndexToDnaPackage,
+
+                "DnaPackageToDnaPackageVersion" => LinkTypes::DnaPackageToDnaPackageVersion,
+
+                "ZomeToDna" => LinkTypes::ZomeToDna,
+
+                "DnaTokenToDna" => LinkTypes::DnaTokenToDna,
+                "IntegrityHashToDna" => LinkTypes::IntegrityHashToDna,
+
                 _ => return Err(guest_error!(format!("Unknown LinkTypes variant: {}", name ))),
             }
         )
@@ -1,14 +1,17 @@
 mod create_entry;
 mod update_entry;
 mod delete_entry;
-// mod create_link;
-// mod delete_link;
+mod create_link;
+mod delete_link;
 
 use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
+    EntryTypesUnit,
     LinkTypes,
+
+    DnaPackageEntry,
 };
 
 use hdi::prelude::*;
@@ -16,6 +19,11 @@ use hdi_extensions::{
     // Macros
     valid, invalid,
 };
+use devhub_types::{
+    check_package_authority,
+};
+
+pub use devhub_types::check_authority;
 
 
 #[hdk_extern]
@@ -28,10 +36,10 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 update_entry::validation( app_entry, action, original_action_hash, original_entry_hash ),
             OpRecord::DeleteEntry { original_action_hash, original_entry_hash, action } =>
                 delete_entry::validation( original_action_hash, original_entry_hash, action ),
-            // OpRecord::CreateLink { base_address, target_address, tag, link_type, action } =>
-            //     create_link::validation( base_address, target_address, link_type, tag, action ),
-            // OpRecord::DeleteLink { original_action_hash, base_address, action } =>
-            //     delete_link::validation( original_action_hash, base_address, action ),
+            OpRecord::CreateLink { base_address, target_address, tag, link_type, action } =>
+                create_link::validation( base_address, target_address, link_type, tag, action ),
+            OpRecord::DeleteLink { original_action_hash, base_address, action } =>
+                delete_link::validation( original_action_hash, base_address, action ),
             // OpRecord::CreateAgent { agent, action: create },
             // OpRecord::UpdateAgent { original_key, new_key, original_action_hash, action: update },
             // OpRecord::CreateCapClaim { action: create },
@@ -47,10 +55,14 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             // OpRecord::InitZomesComplete { action: init_zomes_complete },
             _ => valid!(),
         },
+        // The link base authorities must validate links too, otherwise an invalid link could be
+        // stored (and returned by get_links) without its record ever being checked
+        FlatOp::RegisterCreateLink { base_address, target_address, tag, link_type, action } =>
+            create_link::validation( base_address, target_address, link_type, tag, action ),
+        FlatOp::RegisterDeleteLink { base_address, action, .. } =>
+            delete_link::validation( action.link_add_address.clone(), base_address, action ),
         // FlatOp::StoreEntry(op_entry),
         // FlatOp::RegisterAgentActivity(op_activity),
-        // FlatOp::RegisterCreateLink { base_address, target_address, tag, link_type, action: create_link },
-        // FlatOp::RegisterDeleteLink { original_action, base_address, target_address, tag, link_type, action: delete_link },
         // FlatOp::RegisterUpdate(op_update),
         // FlatOp::RegisterDelete(op_delete),
         _ => valid!(),
@@ -62,3 +74,19 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
 
     result
 }
+
+
+/// Check an agent's authority over a DNA package using the latest revision they know of
+///
+/// See [`devhub_types::check_package_authority`]
+pub fn check_dna_package_authority(
+    dna_package_id: &ActionHash,
+    agent_pubkey: &AgentPubKey,
+    chain_top: &ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+    let dna_package_entry_type : EntryType = EntryTypesUnit::DnaPackage.try_into()?;
+
+    check_package_authority::<DnaPackageEntry,_>(
+        &dna_package_entry_type, dna_package_id, agent_pubkey, chain_top,
+    )
+}
@@ -2,17 +2,28 @@ use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
+
+    DnaEntry,
+    DnaPackageEntry,
+    validation::{
+        check_authority,
+    },
 };
 
 use hdi::prelude::*;
 use hdi_extensions::{
+
     // Macros
     valid, invalid,
 };
+use devhub_types::{
+    check_maintainer_matches_package,
+    check_storage_quota,
+};
 
 pub fn validation(
     app_entry: EntryTypes,
-    _create: Create
+    create: Create
 ) -> ExternResult<ValidateCallbackResult> {
     match app_entry {
         EntryTypes::Dna(dna_entry) => {
@@ -32,6 +43,48 @@ pub fn validation(
                 invalid!(format!("Invalid Coordinators Token; expected {:?}", coordinators_token ))
             }
 
+            // The zome WASM is stored (and counted) in ZomeHub so only the entry itself is charged
+            if let ValidateCallbackResult::Invalid(msg) = check_storage_quota(
+                &create, dna_entry.entry_size()?,
+                |entry| DnaEntry::try_from( entry )?.entry_size(),
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        EntryTypes::DnaPackage(entry) => {
+            //
+            // Check if create author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &create.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        EntryTypes::DnaPackageVersion(entry) => {
+            let dna_package : DnaPackageEntry = must_get_valid_record( entry.for_package.clone() )?.try_into()?;
+
+            // Ensure the DNA reference is a DnaEntry
+            let _ : DnaEntry = must_get_entry( entry.dna_entry.clone() )?.try_into()?;
+
+            //
+            // Check parent maintainer settings
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_matches_package(
+                &dna_package.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
+            }
+
+            //
+            // Check if create author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &create.author )? {
+                invalid!(msg)
+            }
+
             valid!()
         },
         // _ => invalid!(format!("Create validation not implemented for entry type: {:#?}", create.entry_type )),
@@ -0,0 +1,244 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    LinkTypes,
+
+    DnaEntry,
+    DnaPackageEntry,
+    DnaPackageVersionEntry,
+    validation::{
+        check_authority,
+        check_dna_package_authority,
+    },
+};
+
+use hdi::prelude::*;
+use hdi_extensions::{
+    AnyLinkableHashTransformer,
+    verify_app_entry_struct,
+    // Macros
+    valid, invalid,
+};
+use devhub_types::{
+    parse_version_tag,
+    check_tag_unclaimed,
+    check_name_index_link,
+    normalize_name,
+};
+
+
+pub fn validation(
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    link_type: LinkTypes,
+    tag: LinkTag,
+    create: CreateLink,
+) -> ExternResult<ValidateCallbackResult> {
+    match link_type {
+        LinkTypes::Dna => {
+            let agent_pubkey = match base_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link base address must be an agent pubkey; not '{}'",
+                    link_type, base_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+            }
+
+            verify_app_entry_struct::<DnaEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::DnaPackage => {
+            verify_app_entry_struct::<DnaPackageEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::AgentToDnaPackage => {
+            let agent_pubkey = match base_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link base address must be an agent pubkey; not '{}'",
+                    link_type, base_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+            }
+
+            verify_app_entry_struct::<DnaPackageEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::NameToDnaPackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a DnaPackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let dna_package = DnaPackageEntry::try_from( record )?;
+            let name_anchor : AnyLinkableHash = Path::from(
+                vec![ Component::from(dna_package.name.as_bytes().to_vec()) ]
+            ).path_entry_hash()?.into();
+
+            if base_address != name_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for package name '{}'",
+                    link_type, base_address, dna_package.name
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_dna_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::NameIndexToDnaPackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a DnaPackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let dna_package = DnaPackageEntry::try_from( record )?;
+            let expected_tag = normalize_name( &dna_package.name );
+
+            if tag.0 != expected_tag.as_bytes() {
+                invalid!(format!(
+                    "{:?} link tag does not match the normalized package name '{}'",
+                    link_type, expected_tag
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_name_index_link( &base_address, &tag )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_dna_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::DnaPackageToDnaPackageVersion => {
+            verify_app_entry_struct::<DnaPackageEntry>( &base_address )?;
+            verify_app_entry_struct::<DnaPackageVersionEntry>( &target_address )?;
+
+            parse_version_tag( &tag )?;
+
+            let dna_package_id = base_address.must_be_action_hash()?;
+            let dna_package_version_addr = target_address.must_be_action_hash()?;
+
+            let dna_package_version_entry = DnaPackageVersionEntry::try_from(
+                must_get_valid_record( dna_package_version_addr )?
+            )?;
+
+            if dna_package_version_entry.for_package != dna_package_id {
+                invalid!(format!(
+                    "DNA Package Version is not for the base target ({}); expected base target '{}'",
+                    base_address, dna_package_version_entry.for_package
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &dna_package_version_entry.maintainer, &create.author )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_tag_unclaimed( &create )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::ZomeToDna => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a DnaEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            if record.action().author() != &create.author {
+                invalid!(format!(
+                    "{:?} link target ({}) was not created by the link author",
+                    link_type, target_address
+                ))
+            }
+
+            let dna_entry = DnaEntry::try_from( record )?;
+
+            if !dna_entry.resources.values()
+                .any( |hrl| AnyLinkableHash::from( hrl.target.clone() ) == base_address )
+            {
+                invalid!(format!(
+                    "{:?} link base ({}) is not a resource of DNA entry ({})",
+                    link_type, base_address, target_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::DnaTokenToDna => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a DnaEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let dna_entry = DnaEntry::try_from( record )?;
+            let token_anchor : AnyLinkableHash = dna_entry.dna_token.anchor_path().path_entry_hash()?.into();
+
+            if base_address != token_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's DNA token",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::IntegrityHashToDna => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a DnaEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let dna_entry = DnaEntry::try_from( record )?;
+            let integrity_anchor : AnyLinkableHash = dna_entry.dna_token.integrity_anchor_path().path_entry_hash()?.into();
+
+            if base_address != integrity_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's integrity hash",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+    }
+}
@@ -2,6 +2,12 @@ use crate::{
     hdi,
     hdi_extensions,
     EntryTypesUnit,
+
+    DnaPackageEntry,
+    DnaPackageVersionEntry,
+    validation::{
+        check_authority,
+    },
 };
 
 use hdi::prelude::*;
@@ -15,7 +21,7 @@ use hdi_extensions::{
 
 pub fn validation(
     original_action_hash: ActionHash,
-    _original_entry_hash: EntryHash,
+    original_entry_hash: EntryHash,
     delete: Delete
 ) -> ExternResult<ValidateCallbackResult> {
     let create = summon_create_action( &original_action_hash )?;
@@ -31,6 +37,24 @@ pub fn validation(
 
             valid!()
         },
+        EntryTypesUnit::DnaPackage => {
+            let entry : DnaPackageEntry = must_get_entry( original_entry_hash )?.try_into()?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &delete.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        EntryTypesUnit::DnaPackageVersion => {
+            let entry : DnaPackageVersionEntry = must_get_entry( original_entry_hash )?.try_into()?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &delete.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
         // entry_type_unit => invalid!(format!("Delete validation not implemented for entry type: {:?}", entry_type_unit )),
     }
 }
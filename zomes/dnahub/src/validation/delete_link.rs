@@ -0,0 +1,75 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    LinkTypes,
+
+    DnaPackageVersionEntry,
+    validation::{
+        check_authority,
+        check_dna_package_authority,
+    },
+};
+
+use hdi::prelude::*;
+use hdi_extensions::{
+    AnyLinkableHashTransformer,
+    // Macros
+    valid, invalid,
+};
+
+
+pub fn validation(
+    original_action_hash: ActionHash,
+    _base_address: AnyLinkableHash,
+    delete: DeleteLink,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record( original_action_hash )?;
+    let create_link = match record.action() {
+        Action::CreateLink(action) => action,
+        _ => invalid!(format!("Original action hash does not belong to create link action")),
+    };
+    let link_type = match LinkTypes::from_type( create_link.zome_index, create_link.link_type )? {
+        Some(lt) => lt,
+        None => invalid!(format!("No match for LinkTypes")),
+    };
+
+    // The link author can always remove their own links
+    if create_link.author == delete.author {
+        valid!()
+    }
+
+    match link_type {
+        LinkTypes::DnaPackage |
+        LinkTypes::NameToDnaPackage |
+        LinkTypes::NameIndexToDnaPackage => {
+            if let ValidateCallbackResult::Invalid(msg) = check_dna_package_authority(
+                &create_link.target_address.must_be_action_hash()?, &delete.author, &delete.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::DnaPackageToDnaPackageVersion => {
+            let dna_package_version = DnaPackageVersionEntry::try_from(
+                must_get_valid_record( create_link.target_address.must_be_action_hash()? )?
+            )?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &dna_package_version.maintainer, &delete.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::Dna |
+        LinkTypes::AgentToDnaPackage |
+        LinkTypes::ZomeToDna |
+        LinkTypes::DnaTokenToDna |
+        LinkTypes::IntegrityHashToDna => {
+            invalid!(format!(
+                "Not authorized to delete link created by author {}",
+                create_link.author
+            ))
+        },
+    }
+}
@@ -2,25 +2,91 @@ use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
+
+    DnaPackageEntry,
+    DnaPackageVersionEntry,
+    validation::{
+        check_authority,
+    },
 };
 
 use hdi::prelude::*;
 use hdi_extensions::{
     // Macros
-    invalid,
+    valid, invalid,
+};
+use devhub_types::{
+    check_maintainer_unchanged,
 };
 
 
 pub fn validation(
     app_entry: EntryTypes,
-    _update: Update,
+    update: Update,
     _original_action_hash: ActionHash,
-    _original_entry_hash: EntryHash
+    original_entry_hash: EntryHash
 ) -> ExternResult<ValidateCallbackResult> {
     match app_entry {
         EntryTypes::Dna(_) => {
             invalid!(format!("DnaEntry are not intended to be updated"))
         },
+        EntryTypes::DnaPackage(entry) => {
+            let previous_entry : DnaPackageEntry = must_get_entry( original_entry_hash )?
+                .try_into()?;
+
+            //
+            // Check if new maintainer is valid
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
+            }
+
+            //
+            // Check if update author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &update.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        EntryTypes::DnaPackageVersion(entry) => {
+            let previous_entry : DnaPackageVersionEntry = must_get_entry( original_entry_hash )?
+                .try_into()?;
+
+            if entry.for_package != previous_entry.for_package {
+                invalid!(format!(
+                    "DNA Package reference cannot be changed: {} => {}",
+                    previous_entry.for_package, entry.for_package,
+                ))
+            }
+
+            if entry.dna_entry != previous_entry.dna_entry {
+                invalid!(format!(
+                    "DNA reference cannot be changed; Create a new DNA Package Version instead",
+                ))
+            }
+
+            //
+            // Check if new maintainer is valid
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
+            }
+
+            //
+            // Check if update author is authorized
+            //
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &entry.maintainer, &update.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
         // _ => invalid!(format!("Update validation not implemented for entry type: {:#?}", update.entry_type )),
     }
 }
@@ -3,21 +3,26 @@ use crate::{
     hdk_extensions,
     hdi_extensions,
     MY_DNAS_ANCHOR,
+    TypedLinkBase,
 };
 
 use hdk::prelude::*;
+use serde_bytes::ByteBuf;
 use hdk_extensions::{
     must_get,
 };
 use hdi_extensions::{
+    guest_error,
     ScopedTypeConnector,
 };
 use dnahub::{
     EntryTypes,
+    EntryTypesUnit,
     LinkTypes,
     DnaEntry,
     DnaToken,
     IntegritiesToken,
+    integrity_hash_anchor_path,
     CoordinatorsToken,
     hc_crud::{
         Entity,
@@ -28,16 +33,59 @@ use dnahub::{
 use dnahub_sdk::{
     LinkBase,
     DnaEntryInput,
+    DnaTokenInput,
     CreateDnaInput,
     DnaAsset,
+    DnaVerificationReport,
+    DiffDnaEntriesInput,
+    DnaEntryDiff,
+    IngestedDna,
+    Page,
+    PaginatedInput,
+    StorageUsage,
+    verify_dna_entry,
+    ingest_dna_bundle,
+    zomehub_sdk::get_zomehub_memory,
 };
 
 
+fn dna_token_anchor(dna_token: &DnaToken) -> ExternResult<TypedLinkBase> {
+    let anchor_path = dna_token.anchor_path().path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::DnaTokenToDna ) )
+}
+
+
+fn integrity_hash_anchor(integrity_hash: &[u8]) -> ExternResult<TypedLinkBase> {
+    let anchor_path = integrity_hash_anchor_path( integrity_hash ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::IntegrityHashToDna ) )
+}
+
 
 fn create_dna_entry_handler(entry: DnaEntry) -> ExternResult<Entity<DnaEntry>> {
+    // An identical DNA entry is reused instead of creating a duplicate
+    let addr = hash_entry( entry.clone() )?;
+    let existing = dna_entries_by_token( &entry.dna_token )?.into_iter()
+        .find( |entity| entity.address == addr );
+
+    if let Some(entity) = existing {
+        MY_DNAS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+
+        return Ok( entity );
+    }
+
     let entity = create_entity( &entry )?;
 
     MY_DNAS_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+    dna_token_anchor( &entry.dna_token )?.create_link_if_not_exists( &entity.id, () )?;
+    integrity_hash_anchor( &entry.dna_token.integrity_hash )?.create_link_if_not_exists( &entity.id, () )?;
+
+    // Reverse links so that dependents can be found from each zome resource
+    for (name, hrl) in entry.resources.iter() {
+        LinkBase::new( hrl.target.clone(), LinkTypes::ZomeToDna )
+            .create_link_if_not_exists( &entity.id, name.as_bytes().to_vec() )?;
+    }
 
     Ok( entity )
 }
@@ -51,7 +99,20 @@ fn create_dna_entry(input: DnaEntryInput) -> ExternResult<Entity<DnaEntry>> {
 
 #[hdk_extern]
 fn create_dna(input: CreateDnaInput) -> ExternResult<Entity<DnaEntry>> {
-    create_dna_entry_handler( input.try_into()? )
+    let strict = input.strict;
+    let entry : DnaEntry = input.try_into()?;
+
+    if strict {
+        let report = verify_dna_entry( &entry )?;
+
+        if !report.valid {
+            return Err(guest_error!(format!(
+                "DNA failed verification: {:?}", report,
+            )));
+        }
+    }
+
+    create_dna_entry_handler( entry )
 }
 
 
@@ -92,12 +153,85 @@ fn get_dna_entry(addr: AnyDhtHash) -> ExternResult<Entity<DnaEntry>> {
 }
 
 
+fn dna_entries_by_token(dna_token: &DnaToken) -> ExternResult<Vec<Entity<DnaEntry>>> {
+    let dnas = dna_token_anchor( dna_token )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_dna_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( dnas )
+}
+
+
+/// Get the DNA entries that were created with the given DNA token
+#[hdk_extern]
+fn get_dna_entries_by_token(input: DnaTokenInput) -> ExternResult<Vec<Entity<DnaEntry>>> {
+    dna_entries_by_token( &DnaToken::from( input ) )
+}
+
+
+/// Get the DNA entries that share an integrity hash (ie. are network compatible)
+///
+/// DNA entries in the result only differ by their coordinator zomes.
+#[hdk_extern]
+fn get_dnas_with_integrity_hash(integrity_hash: ByteBuf) -> ExternResult<Vec<Entity<DnaEntry>>> {
+    let dnas = integrity_hash_anchor( &integrity_hash )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_dna_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( dnas )
+}
+
+
+/// Unpack a `.dna` bundle saved in ZomeHub's mere_memory and create (or reuse) its zome and DNA
+/// entries
+#[hdk_extern]
+fn ingest_dna(mere_memory_addr: EntryHash) -> ExternResult<IngestedDna> {
+    let (memory, bytes) = get_zomehub_memory( &mere_memory_addr )?;
+
+    ingest_dna_bundle( &bytes, memory.uncompressed_size.unwrap_or( memory.memory_size ) )
+}
+
+
+/// Verify the DNA entry's resources against the zomes in zomehub
+#[hdk_extern]
+fn verify_dna(addr: EntryHash) -> ExternResult<DnaVerificationReport> {
+    let dna_entry : DnaEntry = must_get( &addr )?.try_into()?;
+
+    verify_dna_entry( &dna_entry )
+}
+
+
+/// Compare two DNA entries to see if they are a coordinator update or need a migration
+#[hdk_extern]
+fn diff_dna_entries(input: DiffDnaEntriesInput) -> ExternResult<DnaEntryDiff> {
+    let from : DnaEntry = must_get( &input.from )?.try_into()?;
+    let to : DnaEntry = must_get( &input.to )?.try_into()?;
+
+    dnahub_sdk::diff_dna_entries( &from, &to )
+}
+
+
 #[hdk_extern]
 fn get_dna_asset(addr: EntryHash) -> ExternResult<DnaAsset> {
     Ok( addr.try_into()? )
 }
 
 
+/// Get the installable `.dna` bundle bytes for a DNA entry
+#[hdk_extern]
+fn get_dna_bundle(addr: EntryHash) -> ExternResult<ByteBuf> {
+    let dna_asset : DnaAsset = addr.try_into()?;
+
+    Ok( ByteBuf::from( dna_asset.to_bundle_bytes()? ) )
+}
+
+
 #[hdk_extern]
 fn get_dna_entries_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
     ExternResult<Vec<Entity<DnaEntry>>>
@@ -119,7 +253,78 @@ fn get_dna_entries_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
 }
 
 
+#[hdk_extern]
+fn get_dna_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<DnaEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::Dna );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_entry_hash()?;
+                get_dna_entry( addr.into() ).ok()
+            })
+    )
+}
+
+
+/// Get the DNA entries that reference the given zome resource
+///
+/// The input is an `HRL.target` address, so the results of `get_dependents` in another hub can
+/// be chained into this one using `call_cell`.
+#[hdk_extern]
+fn get_dependents(addr: AnyDhtHash) -> ExternResult<Vec<Entity<DnaEntry>>> {
+    let base = LinkBase::new( addr, LinkTypes::ZomeToDna );
+
+    let mut seen = std::collections::BTreeSet::new();
+    let dependents = base.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            match seen.insert( id.clone() ) {
+                true => get_dna_entry( id.into() ).ok(),
+                false => None,
+            }
+        })
+        .collect();
+
+    Ok( dependents )
+}
+
+
+/// Get the total entry size of the DNAs created by an agent and the storage quota of this DNA
+#[hdk_extern]
+fn get_agent_storage_usage(maybe_agent_id: Option<AgentPubKey>) -> ExternResult<StorageUsage> {
+    let agent_id = match maybe_agent_id {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+
+    dnahub_sdk::get_agent_storage_usage(
+        agent_id,
+        EntryTypesUnit::Dna.try_into()?,
+        |entry| DnaEntry::try_from( entry )?.entry_size(),
+    )
+}
+
+
 #[hdk_extern]
 fn delete_dna(addr: ActionHash) -> ExternResult<ActionHash> {
-    Ok( delete_entity::<DnaEntry,EntryTypes>( &addr )? )
+    let dna_entry = get_dna_entry( addr.clone().into() )?.content;
+
+    for hrl in dna_entry.resources.values() {
+        LinkBase::new( hrl.target.clone(), LinkTypes::ZomeToDna )
+            .delete_all_my_links_to_target( &addr, None )?;
+    }
+
+    let delete_addr = delete_entity::<DnaEntry,EntryTypes>( &addr )?;
+
+    dna_token_anchor( &dna_entry.dna_token )?.delete_all_my_links_to_target( &addr, None )?;
+    integrity_hash_anchor( &dna_entry.dna_token.integrity_hash )?.delete_all_my_links_to_target( &addr, None )?;
+
+    Ok( delete_addr )
 }
@@ -0,0 +1,45 @@
+use crate::{
+    hdk,
+};
+
+use std::ops::Deref;
+use hdk::prelude::*;
+use dnahub::{
+    LinkTypes,
+    DnaPackageVersionEntry,
+    hc_crud::{
+        Entity, EntityId,
+    },
+};
+use dnahub_sdk::{
+    PackageBase,
+    Page,
+    PageQuery,
+    DnaPackageVersionMap,
+};
+
+
+pub struct DnaPackageBase(pub PackageBase<LinkTypes>);
+
+impl Deref for DnaPackageBase {
+    type Target = PackageBase<LinkTypes>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DnaPackageBase {
+    pub fn new(id: &EntityId) -> Self {
+        Self( PackageBase::new( id, LinkTypes::DnaPackageToDnaPackageVersion ) )
+    }
+
+    pub fn versions(&self) -> ExternResult<DnaPackageVersionMap> {
+        self.0.versions( crate::dna_package_version_handlers::get_dna_package_version )
+    }
+    pub fn versions_page(&self, query: &PageQuery) ->
+        ExternResult<Page<(String, Entity<DnaPackageVersionEntry>)>>
+    {
+        self.0.versions_page( query, crate::dna_package_version_handlers::get_dna_package_version )
+    }
+}
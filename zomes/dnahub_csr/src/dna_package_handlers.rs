@@ -0,0 +1,285 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    MY_DNA_PACKS_ANCHOR,
+    ALL_DNA_PACKS_ANCHOR,
+    DNA_PACKS_NAME_INDEX,
+};
+
+use std::collections::BTreeMap;
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+    hdi_extensions::{
+        guest_error,
+        trace_origin_root,
+        ScopedTypeConnector,
+        AnyLinkableHashTransformer,
+    },
+};
+use dnahub::{
+    LinkTypes,
+    RmpvValue,
+    Authority,
+
+    DnaPackageEntry,
+    hc_crud::{
+        Entity, EntityId,
+        EntryModel,
+        create_entity, get_entity, update_entity,
+        UpdateEntityInput,
+    },
+};
+use dnahub_sdk::{
+    LinkBase,
+    normalize_name,
+    CreateDnaPackageInput,
+    Page,
+    PageQuery,
+    PaginatedInput,
+};
+use coop_content_sdk::{
+    get_group_content_latest,
+};
+
+
+#[hdk_extern]
+fn create_dna_package_entry(input: DnaPackageEntry) -> ExternResult<Entity<DnaPackageEntry>> {
+    let entity = create_entity( &input )?;
+
+    MY_DNA_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, () )?;
+    ALL_DNA_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, input.name.clone() )?;
+
+    let anchor_path = Path::from( vec![ Component::from(input.name.as_bytes().to_vec()) ] ).path_entry_hash()?;
+    let name_anchor = LinkBase::new( anchor_path, LinkTypes::NameToDnaPackage );
+    name_anchor.create_link_if_not_exists( &entity.id, () )?;
+
+    DNA_PACKS_NAME_INDEX.add( &input.name, &entity.id )?;
+
+    Ok( entity )
+}
+
+
+#[hdk_extern]
+fn create_dna_package(input: CreateDnaPackageInput) -> ExternResult<Entity<DnaPackageEntry>> {
+    let entry : DnaPackageEntry = input.try_into()?;
+
+    create_dna_package_entry( entry )
+}
+
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UpdateProperties {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub maintainer: Option<Authority>,
+    pub tags: Option<Vec<String>>,
+    pub metadata: Option<BTreeMap<String, RmpvValue>>,
+}
+pub type UpdateInput = UpdateEntityInput<UpdateProperties>;
+
+#[hdk_extern]
+pub fn update_dna_package(input: UpdateInput) -> ExternResult<Entity<DnaPackageEntry>> {
+    debug!("Updating DNA package: {}", input.base );
+    let props = input.properties.clone();
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : DnaPackageEntry, _| {
+	    current.title = props.title
+		.unwrap_or( current.title );
+	    current.description = props.description
+		.unwrap_or( current.description );
+	    current.maintainer = props.maintainer
+		.unwrap_or( current.maintainer );
+	    current.tags = props.tags
+		.or( current.tags );
+	    current.metadata = props.metadata
+		.unwrap_or( current.metadata );
+
+	    Ok( current )
+	})?;
+
+    Ok( entity )
+}
+
+
+#[hdk_extern]
+fn get_dna_package_entry(addr: AnyDhtHash) -> ExternResult<Entity<DnaPackageEntry>> {
+    let record = must_get( &addr )?;
+    let content = DnaPackageEntry::try_from_record( &record )?;
+    let id = record.action_address().to_owned();
+    let addr = hash_entry( content.clone() )?;
+
+    Ok(
+        Entity {
+            id: id.clone(),
+            action: id,
+	    address: addr,
+	    ctype: content.get_type(),
+	    content,
+        }
+    )
+}
+
+#[hdk_extern]
+pub fn get_dna_package(addr: EntityId) -> ExternResult<Entity<DnaPackageEntry>> {
+    let addr = trace_origin_root( &addr )?.0;
+    let record = must_get( &addr )?;
+    let dna_package_entry = DnaPackageEntry::try_from_record( &record )?;
+
+    Ok(match dna_package_entry.maintainer {
+        Authority::Agent(_) => {
+            get_entity( &addr )?
+        },
+        Authority::Group(group_id, _) => {
+            let latest_addr = get_group_content_latest!({
+                group_id: group_id,
+                content_id: addr.clone().into(),
+            })?;
+            let record = must_get( &latest_addr )?;
+            let content = DnaPackageEntry::try_from_record( &record )?;
+            let id = record.action_address().to_owned();
+            let hash = hash_entry( content.clone() )?;
+
+            Entity {
+                id: addr,
+                action: id,
+	        address: hash,
+	        ctype: content.get_type(),
+	        content,
+            }
+        },
+    })
+}
+
+
+#[hdk_extern]
+fn get_all_dna_package_links() ->
+    ExternResult<Vec<Link>>
+{
+    ALL_DNA_PACKS_ANCHOR.get_links( None )
+}
+
+
+#[hdk_extern]
+fn get_all_dna_packages_paginated(input: PageQuery) ->
+    ExternResult<Page<Entity<DnaPackageEntry>>>
+{
+    Ok(
+        ALL_DNA_PACKS_ANCHOR.get_links_page( None, &input )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_dna_package( addr ).ok()
+            })
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_dna_packages_by_name_prefix(prefix: String) ->
+    ExternResult<Vec<Entity<DnaPackageEntry>>>
+{
+    let prefix = normalize_name( &prefix );
+
+    Ok(
+        DNA_PACKS_NAME_INDEX.search( &prefix )?.into_iter()
+            .filter_map( |link| get_dna_package( link.target.into_action_hash()? ).ok() )
+            .filter( |entity| normalize_name( &entity.content.name ).starts_with( &prefix ) )
+            .collect()
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_dna_packages_by_name_fuzzy(query: String) ->
+    ExternResult<Vec<Entity<DnaPackageEntry>>>
+{
+    Ok(
+        DNA_PACKS_NAME_INDEX.fuzzy_search( &query )?.into_iter()
+            .filter_map( |link| get_dna_package( link.target.into_action_hash()? ).ok() )
+            .collect()
+    )
+}
+
+
+#[hdk_extern]
+pub fn get_dna_package_by_name(name: String) -> ExternResult<Entity<DnaPackageEntry>> {
+    let anchor_path = Path::from( vec![ Component::from(name.as_bytes().to_vec()) ] ).path_entry_hash()?;
+    let name_anchor = LinkBase::new( anchor_path, LinkTypes::NameToDnaPackage );
+    let mut all_links = name_anchor.get_links( None )?;
+    all_links.sort_by_key( |link| link.timestamp ); // Ascending timestamp order
+
+    let package_link = all_links.first() // Select oldest timestamp
+        .ok_or(guest_error!(format!(
+            "No package found for name '{}'",
+            name
+        )))?.to_owned();
+
+    get_dna_package( package_link.target.must_be_action_hash()? )
+}
+
+
+#[hdk_extern]
+fn get_dna_packages_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
+    ExternResult<Vec<Entity<DnaPackageEntry>>>
+{
+    let agent_id = match maybe_agent_id {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToDnaPackage );
+
+    let dna_packages = agent_anchor.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_dna_package( id ).ok()
+        })
+        .collect();
+
+    Ok( dna_packages )
+}
+
+
+#[hdk_extern]
+fn get_dna_packages_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<DnaPackageEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToDnaPackage );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_dna_package( addr ).ok()
+            })
+    )
+}
+
+
+#[hdk_extern]
+pub fn delete_dna_package(id: EntityId) -> ExternResult<bool> {
+    let dna_package = get_dna_package( id.clone() )?.content;
+
+    {
+        let deleted_links = MY_DNA_PACKS_ANCHOR.delete_all_my_links_to_target( &id, None )?;
+        debug!("Deleted 'my' DNA package links: {:?}", deleted_links );
+    }
+
+    {
+        let deleted_links = ALL_DNA_PACKS_ANCHOR.delete_all_my_links_to_target( &id, None )?;
+        debug!("Deleted 'all' DNA package links: {:?}", deleted_links );
+    }
+
+    let anchor_path = Path::from( vec![ Component::from(dna_package.name.as_bytes().to_vec()) ] ).path_entry_hash()?;
+    let name_anchor = LinkBase::new( anchor_path, LinkTypes::NameToDnaPackage );
+    name_anchor.delete_all_my_links_to_target( &id, None )?;
+
+    DNA_PACKS_NAME_INDEX.remove( &dna_package.name, &id )?;
+
+    Ok(true)
+}
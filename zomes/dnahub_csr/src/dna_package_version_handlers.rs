@@ -0,0 +1,226 @@
+use crate::{
+    hdk,
+    hdk_extensions,
+    DnaPackageBase,
+};
+use std::collections::BTreeMap;
+
+use hdk::prelude::*;
+use hdk_extensions::{
+    must_get,
+    hdi_extensions::{
+        trace_origin_root,
+        ScopedTypeConnector,
+    },
+};
+use dnahub::{
+    // LinkTypes,
+    RmpvValue,
+    Authority,
+
+    DnaPackageVersionEntry,
+    hc_crud::{
+        Entity, EntityId,
+        EntryModel,
+        create_entity, get_entity, update_entity,
+        UpdateEntityInput,
+    },
+};
+use dnahub_sdk::{
+    // LinkBase,
+    EntityPointerMap,
+    DnaPackageVersionMap,
+    CreateDnaPackageVersionInput,
+    Page,
+    PaginatedInput,
+};
+use coop_content_sdk::{
+    get_group_content_latest,
+};
+
+
+
+#[hdk_extern]
+fn create_dna_package_version_entry(input: DnaPackageVersionEntry) ->
+    ExternResult<Entity<DnaPackageVersionEntry>>
+{
+    let entity = create_entity( &input )?;
+
+    // TODO: Link from package
+
+    Ok( entity )
+}
+
+
+#[hdk_extern]
+fn create_dna_package_version(input: CreateDnaPackageVersionInput) ->
+    ExternResult<Entity<DnaPackageVersionEntry>>
+{
+    let entry : DnaPackageVersionEntry = input.clone().try_into()?;
+
+    let entity = create_dna_package_version_entry( entry )?;
+
+    create_dna_package_link_to_version(CreateLinkDnaPackageVersionInput {
+	version: input.version,
+	dna_package_id: input.for_package,
+	dna_package_version_addr: entity.id.clone(),
+    })?;
+
+    Ok( entity )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateLinkDnaPackageVersionInput {
+    pub version: String,
+    pub dna_package_id: EntityId,
+    pub dna_package_version_addr: ActionHash,
+}
+
+#[hdk_extern]
+pub fn create_dna_package_link_to_version(input: CreateLinkDnaPackageVersionInput) ->
+    ExternResult<ActionHash>
+{
+    let dna_base = DnaPackageBase::new( &input.dna_package_id );
+
+    dna_base.create_version_link( &input.dna_package_version_addr, input.version.as_str() )
+}
+
+
+#[hdk_extern]
+pub fn get_dna_package_version_links(dna_package_id: EntityId) ->
+    ExternResult<Vec<Link>>
+{
+    let base = DnaPackageBase::new( &dna_package_id );
+
+    base.version_links()
+}
+
+
+#[hdk_extern]
+pub fn get_dna_package_version_targets(dna_package_id: EntityId) ->
+    ExternResult<EntityPointerMap>
+{
+    let base = DnaPackageBase::new( &dna_package_id );
+
+    base.version_targets()
+}
+
+
+#[hdk_extern]
+pub fn get_dna_package_versions(dna_package_id: EntityId) ->
+    ExternResult<DnaPackageVersionMap>
+{
+    let base = DnaPackageBase::new( &dna_package_id );
+
+    base.versions()
+}
+
+
+#[hdk_extern]
+pub fn get_dna_package_versions_paginated(input: PaginatedInput<EntityId>) ->
+    ExternResult<Page<(String, Entity<DnaPackageVersionEntry>)>>
+{
+    let base = DnaPackageBase::new( &input.input );
+
+    base.versions_page( &input.page )
+}
+
+
+#[hdk_extern]
+fn get_dna_package_version_entry(addr: AnyDhtHash) ->
+    ExternResult<Entity<DnaPackageVersionEntry>>
+{
+    let record = must_get( &addr )?;
+    let content = DnaPackageVersionEntry::try_from_record( &record )?;
+    let id = record.action_address().to_owned();
+    let addr = hash_entry( content.clone() )?;
+
+    Ok(
+        Entity {
+            id: id.clone(),
+            action: id,
+	    address: addr,
+	    ctype: content.get_type(),
+	    content,
+        }
+    )
+}
+
+#[hdk_extern]
+pub fn get_dna_package_version(addr: EntityId) -> ExternResult<Entity<DnaPackageVersionEntry>> {
+    let addr = trace_origin_root( &addr )?.0;
+    let record = must_get( &addr )?;
+    let dna_package_version_entry = DnaPackageVersionEntry::try_from_record( &record )?;
+
+    Ok(match dna_package_version_entry.maintainer {
+        Authority::Agent(_) => {
+            get_entity( &addr )?
+        },
+        Authority::Group(group_id, _) => {
+            let latest_addr = get_group_content_latest!({
+                group_id: group_id,
+                content_id: addr.clone().into(),
+            })?;
+            let record = must_get( &latest_addr )?;
+            let content = DnaPackageVersionEntry::try_from_record( &record )?;
+            let id = record.action_address().to_owned();
+            let hash = hash_entry( content.clone() )?;
+
+            Entity {
+                id: addr,
+                action: id,
+	        address: hash,
+	        ctype: content.get_type(),
+	        content,
+            }
+        },
+    })
+}
+
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UpdateProperties {
+    pub maintainer: Option<Authority>,
+    pub readme: Option<EntryHash>,
+    pub changelog: Option<EntryHash>,
+    pub source_code_revision_uri: Option<String>,
+    pub metadata: Option<BTreeMap<String, RmpvValue>>,
+}
+pub type UpdateInput = UpdateEntityInput<UpdateProperties>;
+
+#[hdk_extern]
+pub fn update_dna_package_version(input: UpdateInput) -> ExternResult<Entity<DnaPackageVersionEntry>> {
+    debug!("Updating DNA package version: {}", input.base );
+    let props = input.properties.clone();
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : DnaPackageVersionEntry, _| {
+	    current.maintainer = props.maintainer
+		.unwrap_or( current.maintainer );
+	    current.readme = props.readme
+		.or( current.readme );
+	    current.changelog = props.changelog
+		.or( current.changelog );
+	    current.source_code_revision_uri = props.source_code_revision_uri
+		.or( current.source_code_revision_uri );
+	    current.metadata = props.metadata
+		.unwrap_or( current.metadata );
+
+	    Ok( current )
+	})?;
+
+    Ok( entity )
+}
+
+
+#[hdk_extern]
+pub fn delete_dna_package_version(id: EntityId) -> ExternResult<bool> {
+    let package_version = get_dna_package_version( id.clone() )?.content;
+    let dna_base = DnaPackageBase::new( &package_version.for_package );
+
+    dna_base.version_link_base().delete_all_my_links_to_target( &id, None )?;
+
+    Ok(true)
+}
@@ -1,9 +1,13 @@
 mod dna_handlers;
+mod dna_package_handlers;
+mod dna_package_version_handlers;
+mod dna_package_base;
 
 pub use dnahub::hdi;
 pub use dnahub::hdi_extensions;
 pub use devhub_sdk::hdk;
 pub use devhub_sdk::hdk_extensions;
+pub use dna_package_base::*;
 
 use lazy_static::lazy_static;
 use hdk::prelude::*;
@@ -15,15 +19,28 @@ use dnahub::{
 };
 use dnahub_sdk::{
     LinkBase,
+    NameIndex,
+    devhub_types::{
+        DevHubProperties,
+    },
 };
 
 
 pub type TypedLinkBase = LinkBase<LinkTypes>;
+pub type TypedNameIndex = NameIndex<LinkTypes>;
 
 lazy_static! {
     pub static ref AGENT_ID : AgentPubKey = agent_id().expect("Unable to obtain current Agent context");
 
+    pub static ref ALL_DNA_PACKS_ANCHOR_HASH : EntryHash = Path::from( vec![ Component::from("all_dna_packages".as_bytes().to_vec()) ] )
+        .path_entry_hash()
+        .expect("Unable to derive all_dna_packages anchor");
+    pub static ref ALL_DNA_PACKS_ANCHOR : TypedLinkBase = LinkBase::new( ALL_DNA_PACKS_ANCHOR_HASH.clone(), LinkTypes::DnaPackage );
+
     pub static ref MY_DNAS_ANCHOR : TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::Dna );
+    pub static ref MY_DNA_PACKS_ANCHOR : TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToDnaPackage );
+
+    pub static ref DNA_PACKS_NAME_INDEX : TypedNameIndex = NameIndex::new( LinkTypes::NameIndexToDnaPackage );
 }
 
 
@@ -33,6 +50,15 @@ fn init(_: ()) -> ExternResult<InitCallbackResult> {
     let zome_name = zome_settings.name;
     debug!("'{}' init", zome_name );
 
+    // Every upload would fail validation with malformed DNA properties
+    let properties = match DevHubProperties::get() {
+        Ok(properties) => properties,
+        Err(err) => return Ok(InitCallbackResult::Fail(format!(
+            "Invalid DNA properties: {:?}", err,
+        ))),
+    };
+    debug!("Storage quota: {:?}", properties.storage_quota );
+
     let granted_functions = zome_settings.extern_fns.into_iter()
         .filter_map(|fn_name| match fn_name.as_ref().starts_with("get_") {
             true => Some(( zome_name.0.as_ref(), fn_name.0 )),
@@ -20,8 +20,13 @@ use hc_crud::{
 
 
 /// The entry types defined for this integrity zome
+///
+/// `ZomePackageVersion` is much larger than the other variants but boxing it would break the
+/// `scoped_type_connector!` and `entry_model!` conversions, and these values only live for the
+/// length of a validation or CRUD call.
 #[hdk_entry_types]
 #[unit_enum(EntryTypesUnit)]
+#[allow(clippy::large_enum_variant)]
 pub enum EntryTypes {
     #[entry_type]
     Zome(ZomeEntry),
@@ -29,6 +34,10 @@ pub enum EntryTypes {
     ZomePackage(ZomePackageEntry),
     #[entry_type]
     ZomePackageVersion(ZomePackageVersionEntry),
+    #[entry_type]
+    Review(ReviewEntry),
+    #[entry_type]
+    RebuildAttestation(RebuildAttestationEntry),
 }
 
 scoped_type_connector!(
@@ -43,11 +52,21 @@ scoped_type_connector!(
     EntryTypesUnit::ZomePackageVersion,
     EntryTypes::ZomePackageVersion(ZomePackageVersionEntry)
 );
+scoped_type_connector!(
+    EntryTypesUnit::Review,
+    EntryTypes::Review(ReviewEntry)
+);
+scoped_type_connector!(
+    EntryTypesUnit::RebuildAttestation,
+    EntryTypes::RebuildAttestation(RebuildAttestationEntry)
+);
 
 // Entity implementations
 entry_model!( EntryTypes::Zome( ZomeEntry ) );
 entry_model!( EntryTypes::ZomePackage( ZomePackageEntry ) );
 entry_model!( EntryTypes::ZomePackageVersion( ZomePackageVersionEntry ) );
+entry_model!( EntryTypes::Review( ReviewEntry ) );
+entry_model!( EntryTypes::RebuildAttestation( RebuildAttestationEntry ) );
 
 
 
@@ -62,11 +81,23 @@ pub enum LinkTypes {
 
     NameToGroup,
     NameToZomePackage,
+    TagToZomePackage,
+    NameIndexToZomePackage,
 
     AllAgentsToAgent,
     AllOrgsToGroup,
 
+    /// Tagged with the version; a tag is unique per author only, so the oldest claim wins
     ZomePackageToZomePackageVersion,
+
+    ZomePackageVersionToDependent,
+
+    WasmHashToZome,
+
+    ZomePackageVersionToReview,
+    AgentToReview,
+
+    ZomePackageVersionToRebuildAttestation,
 }
 
 impl TryFrom<String> for LinkTypes {
@@ -83,12 +114,23 @@ impl TryFrom<String> for LinkTypes {
 
                 "NameToGroup" => LinkTypes::NameToGroup,
                 "NameToZomePackage" => LinkTypes::NameToZomePackage,
+                "TagToZomePackage" => LinkTypes::TagToZomePackage,
+                "NameIndexToZomePackage" => LinkTypes::NameIndexToZomePackage,
 
                 "AllAgentsToAgent" => LinkTypes::AllAgentsToAgent,
                 "AllOrgsToGroup" => LinkTypes::AllOrgsToGroup,
 
                 "ZomePackageToZomePackageVersion" => LinkTypes::ZomePackageToZomePackageVersion,
 
+                "ZomePackageVersionToDependent" => LinkTypes::ZomePackageVersionToDependent,
+
+                "WasmHashToZome" => LinkTypes::WasmHashToZome,
+
+                "ZomePackageVersionToReview" => LinkTypes::ZomePackageVersionToReview,
+                "AgentToReview" => LinkTypes::AgentToReview,
+
+                "ZomePackageVersionToRebuildAttestation" => LinkTypes::ZomePackageVersionToRebuildAttestation,
+
                 _ => return Err(guest_error!(format!("Unknown LinkTypes variant: {}", name ))),
             }
         )
@@ -1,30 +1,30 @@
 mod create_entry;
 mod update_entry;
 mod delete_entry;
-// mod create_link;
-// mod delete_link;
+mod create_link;
+mod delete_link;
 
 use crate::{
     hdi,
     hdi_extensions,
     EntryTypes,
+    EntryTypesUnit,
     LinkTypes,
 
-    Authority,
+    ZomePackageEntry,
 };
 
 use hdi::prelude::*;
 use hdi_extensions::{
-    trace_origin_root,
-    summon_app_entry,
-
     // Macros
     valid, invalid,
 };
-use coop_content_types::{
-    GroupEntry,
+use devhub_types::{
+    check_package_authority,
 };
 
+pub use devhub_types::check_authority;
+
 
 #[hdk_extern]
 fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
@@ -36,10 +36,10 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 update_entry::validation( app_entry, action, original_action_hash, original_entry_hash ),
             OpRecord::DeleteEntry { original_action_hash, original_entry_hash, action } =>
                 delete_entry::validation( original_action_hash, original_entry_hash, action ),
-            // OpRecord::CreateLink { base_address, target_address, tag, link_type, action } =>
-            //     create_link::validation( base_address, target_address, link_type, tag, action ),
-            // OpRecord::DeleteLink { original_action_hash, base_address, action } =>
-            //     delete_link::validation( original_action_hash, base_address, action ),
+            OpRecord::CreateLink { base_address, target_address, tag, link_type, action } =>
+                create_link::validation( base_address, target_address, link_type, tag, action ),
+            OpRecord::DeleteLink { original_action_hash, base_address, action } =>
+                delete_link::validation( original_action_hash, base_address, action ),
             // OpRecord::CreateAgent { agent, action: create },
             // OpRecord::UpdateAgent { original_key, new_key, original_action_hash, action: update },
             // OpRecord::CreateCapClaim { action: create },
@@ -55,10 +55,14 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             // OpRecord::InitZomesComplete { action: init_zomes_complete },
             _ => valid!(),
         },
+        // The link base authorities must validate links too, otherwise an invalid link could be
+        // stored (and returned by get_links) without its record ever being checked
+        FlatOp::RegisterCreateLink { base_address, target_address, tag, link_type, action } =>
+            create_link::validation( base_address, target_address, link_type, tag, action ),
+        FlatOp::RegisterDeleteLink { base_address, action, .. } =>
+            delete_link::validation( action.link_add_address.clone(), base_address, action ),
         // FlatOp::StoreEntry(op_entry),
         // FlatOp::RegisterAgentActivity(op_activity),
-        // FlatOp::RegisterCreateLink { base_address, target_address, tag, link_type, action: create_link },
-        // FlatOp::RegisterDeleteLink { original_action, base_address, target_address, tag, link_type, action: delete_link },
         // FlatOp::RegisterUpdate(op_update),
         // FlatOp::RegisterDelete(op_delete),
         _ => valid!(),
@@ -72,38 +76,18 @@ fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
 }
 
 
-pub fn check_authority(
-    authority: &Authority,
+/// Check an agent's authority over a zome package using the latest revision they know of
+///
+/// See [`devhub_types::check_package_authority`]
+pub fn check_zome_package_authority(
+    zome_package_id: &ActionHash,
     agent_pubkey: &AgentPubKey,
+    chain_top: &ActionHash,
 ) -> ExternResult<ValidateCallbackResult> {
-    match authority {
-        Authority::Agent(authority_agent) => {
-            if agent_pubkey != authority_agent {
-                invalid!(format!(
-                    "{} is not the maintainer ({})",
-                    agent_pubkey, authority_agent,
-                ))
-            }
-        },
-        Authority::Group(group_id, group_rev) => {
-            let group : GroupEntry = summon_app_entry( &group_rev.to_owned().into() )?;
-
-            if !group.is_contributor( &agent_pubkey ) {
-                invalid!(format!(
-                    "{} is not authorized in group {}",
-                    agent_pubkey, group_id,
-                ))
-            }
+    let zome_package_entry_type : EntryType = EntryTypesUnit::ZomePackage.try_into()?;
 
-            // Check that group_rev belongs to group_id
-            if *group_id != trace_origin_root( group_rev )?.0 {
-                invalid!(format!(
-                    "Group rev {} is not a descendant of group ID {}",
-                    group_rev, group_id,
-                ))
-            }
-        },
-    }
-
-    valid!()
+    check_package_authority::<ZomePackageEntry,_>(
+        &zome_package_entry_type, zome_package_id, agent_pubkey, chain_top,
+    )
 }
+
@@ -3,9 +3,12 @@ use crate::{
     hdi_extensions,
     mere_memory_types, // from zomehub_types
     EntryTypes,
+    LinkTypes,
 
-    Authority,
+    ZomeEntry,
     ZomePackageEntry,
+    ZomePackageVersionEntry,
+    detect_zome_type,
     validation::{
         check_authority,
     },
@@ -13,7 +16,6 @@ use crate::{
 
 use hdi::prelude::*;
 use hdi_extensions::{
-    trace_origin_root,
 
     // Macros
     valid, invalid,
@@ -21,6 +23,13 @@ use hdi_extensions::{
 use mere_memory_types::{
     MemoryEntry,
 };
+use devhub_types::{
+    check_maintainer_matches_package,
+    check_storage_quota,
+    check_review,
+    check_rebuild_attestation,
+    check_not_version_maintainer,
+};
 
 
 pub fn validation(
@@ -47,9 +56,36 @@ pub fn validation(
                 ))
             }
 
+            if let ValidateCallbackResult::Invalid(msg) = check_storage_quota(
+                &create, zome_entry.file_size,
+                |entry| Ok( ZomeEntry::try_from( entry )?.file_size ),
+            )? {
+                invalid!(msg)
+            }
+
+            // The wasm is not reassembled here; the exports are read from it by the CSR
+            if zome_entry.detected_zome_type != detect_zome_type( &zome_entry.exports ) {
+                invalid!(format!(
+                    "ZomeEntry detected zome type does not match its exports"
+                ))
+            }
+
+            if let Some(detected_zome_type) = zome_entry.detected_zome_type {
+                if detected_zome_type != zome_entry.zome_type {
+                    invalid!(format!(
+                        "ZomeEntry is declared as '{}' but its exports indicate '{}'",
+                        String::from( zome_entry.zome_type ), String::from( detected_zome_type ),
+                    ))
+                }
+            }
+
             valid!()
         },
         EntryTypes::ZomePackage(entry) => {
+            if entry.deprecation.is_some() {
+                invalid!(format!("Zome Package cannot be deprecated when it is created"))
+            }
+
             //
             // Check if create author is authorized
             //
@@ -60,54 +96,19 @@ pub fn validation(
             valid!()
         },
         EntryTypes::ZomePackageVersion(entry) => {
-            let zome_package : ZomePackageEntry = must_get_valid_record( entry.for_package )?.try_into()?;
+            if entry.yanked.is_some() {
+                invalid!(format!("Zome Package Version cannot be yanked when it is created"))
+            }
+
+            let zome_package : ZomePackageEntry = must_get_valid_record( entry.for_package.clone() )?.try_into()?;
 
             //
             // Check parent maintainer settings
             //
-            match ( &zome_package.maintainer, &entry.maintainer ) {
-
-                // Should have matching agent authority
-                (
-                    Authority::Agent(expected_agent),
-                    Authority::Agent(agent)
-                ) => {
-                    if expected_agent != agent {
-                        invalid!(format!(
-                            "Maintainer agent must match parent package: {} != {}",
-                            expected_agent, agent,
-                        ))
-                    }
-                },
-
-                // Should have matching group ID
-                (
-                    Authority::Group(expected_group_id, _),
-                    Authority::Group(group_id, group_rev)
-                ) => {
-                    // Ensure same group ID
-                    if expected_group_id != group_id {
-                        invalid!(format!(
-                            "Maintainer group must match parent package: {} != {}",
-                            expected_group_id, group_id,
-                        ))
-                    }
-
-                    // Ensure group rev is related to group ID
-                    if trace_origin_root( &group_rev )?.0 != *group_id {
-                        invalid!(format!(
-                            "Maintainer group revision ({}) must be an evolution of group ID ({})",
-                            group_rev, group_id,
-                        ))
-                    }
-                },
-
-                (expected_maintainer, maintainer) => {
-                    invalid!(format!(
-                        "Maintainer type must match parent package: {:?} != {:?}",
-                        maintainer, expected_maintainer,
-                    ))
-                },
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_matches_package(
+                &zome_package.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
             }
 
             //
@@ -117,8 +118,101 @@ pub fn validation(
                 invalid!(msg);
             }
 
+            //
+            // Check dependencies
+            //
+            for dependency in entry.dependencies.unwrap_or_default() {
+                if dependency.package == entry.for_package {
+                    invalid!(format!(
+                        "Dependency '{}' cannot reference its own package ({})",
+                        dependency.name, dependency.package,
+                    ))
+                }
+
+                let version_req = match semver::VersionReq::parse( &dependency.version_req ) {
+                    Ok(version_req) => version_req,
+                    Err(err) => invalid!(format!(
+                        "Dependency '{}' version requirement '{}' is not a valid semver requirement: {}",
+                        dependency.name, dependency.version_req, err,
+                    )),
+                };
+
+                let _ : ZomePackageEntry = must_get_valid_record( dependency.package.clone() )?.try_into()?;
+
+                // Dependencies must pin a version through the package's version link
+                let (version_id, version_link) = match (dependency.version, dependency.version_link) {
+                    (Some(version_id), Some(version_link)) => (version_id, version_link),
+                    (None, _) => invalid!(format!(
+                        "Dependency '{}' must pin a version", dependency.name,
+                    )),
+                    (_, None) => invalid!(format!(
+                        "Dependency '{}' must reference the pinned version's tag link", dependency.name,
+                    )),
+                };
+
+                let vtag = match must_get_valid_record( version_link.clone() )?.action() {
+                    Action::CreateLink(link) if link.base_address == dependency.package.clone().into()
+                        && link.target_address == version_id.clone().into()
+                        && LinkTypes::from_type( link.zome_index, link.link_type )?
+                            == Some(LinkTypes::ZomePackageToZomePackageVersion)
+                        => String::from_utf8( link.tag.0.clone() ).ok(),
+                    _ => invalid!(format!(
+                        "Dependency '{}' version link ({}) is not a link from package ({}) to version ({})",
+                        dependency.name, version_link, dependency.package, version_id,
+                    )),
+                };
+
+                match vtag.as_deref().map( semver::Version::parse ) {
+                    Some(Ok(version)) if version_req.matches( &version ) => (),
+                    Some(Ok(version)) => invalid!(format!(
+                        "Dependency '{}' pinned version '{}' does not satisfy requirement '{}'",
+                        dependency.name, version, dependency.version_req,
+                    )),
+                    _ => invalid!(format!(
+                        "Dependency '{}' pinned version tag {:?} is not a valid semver",
+                        dependency.name, vtag,
+                    )),
+                }
+
+                let version : ZomePackageVersionEntry = must_get_valid_record( version_id.clone() )?.try_into()?;
+
+                if version.for_package != dependency.package {
+                    invalid!(format!(
+                        "Dependency '{}' pinned version ({}) does not belong to package ({})",
+                        dependency.name, version_id, dependency.package,
+                    ))
+                }
+
+                let zome : ZomeEntry = must_get_entry( version.zome_entry )?.try_into()?;
+
+                if zome.hash != dependency.hash {
+                    invalid!(format!(
+                        "Dependency '{}' hash does not match the pinned version's zome hash: {} != {}",
+                        dependency.name, dependency.hash, zome.hash,
+                    ))
+                }
+            }
+
             valid!()
         },
+        EntryTypes::Review(entry) => {
+            if let ValidateCallbackResult::Invalid(msg) = check_review( &entry, &create.author )? {
+                invalid!(msg)
+            }
+
+            let version : ZomePackageVersionEntry = must_get_valid_record( entry.for_version.clone() )?.try_into()?;
+
+            check_not_version_maintainer( &entry.for_version, &version.maintainer, &create.author, "review" )
+        },
+        EntryTypes::RebuildAttestation(entry) => {
+            if let ValidateCallbackResult::Invalid(msg) = check_rebuild_attestation( &entry, &create.author )? {
+                invalid!(msg)
+            }
+
+            let version : ZomePackageVersionEntry = must_get_valid_record( entry.for_version.clone() )?.try_into()?;
+
+            check_not_version_maintainer( &entry.for_version, &version.maintainer, &create.author, "attest" )
+        },
         // _ => invalid!(format!("Create validation not implemented for entry type: {:#?}", create.entry_type )),
     }
 }
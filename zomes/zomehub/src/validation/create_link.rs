@@ -0,0 +1,380 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    LinkTypes,
+
+    ZomeEntry,
+    ZomePackageEntry,
+    ZomePackageVersionEntry,
+    tag_anchor_path,
+    ReviewEntry,
+    RebuildAttestationEntry,
+    validation::{
+        check_authority,
+        check_zome_package_authority,
+    },
+};
+
+use hdi::prelude::*;
+use hdi_extensions::{
+    AnyLinkableHashTransformer,
+    summon_app_entry,
+    verify_app_entry_struct,
+    // Macros
+    valid, invalid,
+};
+use devhub_types::{
+    parse_version_tag,
+    check_tag_unclaimed,
+    check_name_index_link,
+    normalize_name,
+};
+use coop_content_types::{
+    GroupEntry,
+};
+
+
+pub fn validation(
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    link_type: LinkTypes,
+    tag: LinkTag,
+    create: CreateLink,
+) -> ExternResult<ValidateCallbackResult> {
+    match link_type {
+        LinkTypes::ZomePackage => {
+            verify_app_entry_struct::<ZomePackageEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::ZomePackageToZomePackageVersion => {
+            verify_app_entry_struct::<ZomePackageEntry>( &base_address )?;
+            verify_app_entry_struct::<ZomePackageVersionEntry>( &target_address )?;
+
+            parse_version_tag( &tag )?;
+
+            let zome_package_id = base_address.must_be_action_hash()?;
+            let zome_package_version_addr = target_address.must_be_action_hash()?;
+
+            let zome_package_version_entry = ZomePackageVersionEntry::try_from(
+                must_get_valid_record( zome_package_version_addr )?
+            )?;
+
+            if zome_package_version_entry.for_package != zome_package_id {
+                invalid!(format!(
+                    "Zome Package Version is not for the base target ({}); expected base target '{}'",
+                    base_address, zome_package_version_entry.for_package
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &zome_package_version_entry.maintainer, &create.author )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_tag_unclaimed( &create )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::AgentToZome => {
+            let agent_pubkey = match base_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link base address must be an agent pubkey; not '{}'",
+                    link_type, base_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+            }
+
+            verify_app_entry_struct::<ZomeEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::AgentToZomePackage => {
+            let agent_pubkey = match base_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link base address must be an agent pubkey; not '{}'",
+                    link_type, base_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+            }
+
+            verify_app_entry_struct::<ZomePackageEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::AgentToZomePackageVersion => {
+            let agent_pubkey = match base_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link base address must be an agent pubkey; not '{}'",
+                    link_type, base_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+            }
+
+            verify_app_entry_struct::<ZomePackageVersionEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::NameToZomePackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a ZomePackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let zome_package = ZomePackageEntry::try_from( record )?;
+            let name_anchor : AnyLinkableHash = Path::from(
+                vec![ Component::from(zome_package.name.as_bytes().to_vec()) ]
+            ).path_entry_hash()?.into();
+
+            if base_address != name_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for package name '{}'",
+                    link_type, base_address, zome_package.name
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_zome_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::NameIndexToZomePackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a ZomePackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let zome_package = ZomePackageEntry::try_from( record )?;
+            let expected_tag = normalize_name( &zome_package.name );
+
+            if tag.0 != expected_tag.as_bytes() {
+                invalid!(format!(
+                    "{:?} link tag does not match the normalized package name '{}'",
+                    link_type, expected_tag
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_name_index_link( &base_address, &tag )? {
+                invalid!(msg)
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_zome_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::TagToZomePackage => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a ZomePackageEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            ZomePackageEntry::try_from( record )?;
+
+            let tag_name = match String::from_utf8( tag.0.to_owned() ) {
+                Ok(name) => name,
+                Err(err) => invalid!(format!(
+                    "{:?} link tag must be a UTF-8 string: {}",
+                    link_type, err
+                )),
+            };
+
+            if tag_name != tag_name.to_lowercase() {
+                invalid!(format!(
+                    "{:?} link tag must be lowercase; not '{}'",
+                    link_type, tag_name
+                ))
+            }
+
+            let tag_anchor : AnyLinkableHash = tag_anchor_path( &tag_name )
+                .path_entry_hash()?.into();
+
+            if base_address != tag_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for tag '{}'",
+                    link_type, base_address, tag_name
+                ))
+            }
+
+            if let ValidateCallbackResult::Invalid(msg) = check_zome_package_authority(
+                &target_address.must_be_action_hash()?, &create.author, &create.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::NameToGroup => {
+            // Agent based links are personal bookmarks for the group name
+            if let Some(agent_pubkey) = base_address.clone().into_agent_pub_key() {
+                if agent_pubkey != create.author {
+                    invalid!(format!("Not authorized to create link based on agent '{}'", agent_pubkey ))
+                }
+            }
+
+            verify_app_entry_struct::<GroupEntry>( &target_address )?;
+
+            valid!()
+        },
+        LinkTypes::AllOrgsToGroup => {
+            let group : GroupEntry = summon_app_entry( &target_address )?;
+
+            if !group.is_contributor( &create.author ) {
+                invalid!(format!(
+                    "Not authorized to create link; {} is not a contributor of group {}",
+                    create.author, target_address,
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::AllAgentsToAgent => {
+            let agent_pubkey = match target_address.clone().into_agent_pub_key() {
+                Some(hash) => hash,
+                None => invalid!(format!(
+                    "{:?} link target address must be an agent pubkey; not '{}'",
+                    link_type, target_address
+                )),
+            };
+
+            if agent_pubkey != create.author {
+                invalid!(format!("Not authorized to create link targeting agent '{}'", agent_pubkey ))
+            }
+
+            valid!()
+        },
+        LinkTypes::ZomePackageVersionToDependent => {
+            let version_id = base_address.must_be_action_hash()?;
+            let dependent = ZomePackageVersionEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if !dependent.dependencies.unwrap_or_default().iter()
+                .any( |dependency| dependency.version.as_ref() == Some( &version_id ) )
+            {
+                invalid!(format!(
+                    "{:?} link target ({}) does not depend on base version ({})",
+                    link_type, target_address, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::WasmHashToZome => {
+            let record = must_get_valid_record( target_address.must_be_action_hash()? )?;
+
+            if !matches!( record.action(), Action::Create(_) ) {
+                invalid!(format!(
+                    "{:?} link target must be the create action of a ZomeEntry; not '{}'",
+                    link_type, target_address
+                ))
+            }
+
+            let zome_entry = ZomeEntry::try_from( record )?;
+            let hash_anchor : AnyLinkableHash = zome_entry.hash_anchor_path().path_entry_hash()?.into();
+
+            if base_address != hash_anchor {
+                invalid!(format!(
+                    "{:?} link base ({}) does not match the anchor for the target's wasm hash",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::ZomePackageVersionToReview => {
+            let review = ReviewEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != review.reviewer {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the reviewer",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != review.for_version.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the reviewed version",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::AgentToReview => {
+            let review = ReviewEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != review.reviewer {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the reviewer",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != review.reviewer.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the reviewer",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::ZomePackageVersionToRebuildAttestation => {
+            let attestation = RebuildAttestationEntry::try_from(
+                must_get_valid_record( target_address.must_be_action_hash()? )?
+            )?;
+
+            if create.author != attestation.attester {
+                invalid!(format!(
+                    "{:?} link author ({}) is not the attester",
+                    link_type, create.author
+                ))
+            }
+
+            if base_address != attestation.for_version.into() {
+                invalid!(format!(
+                    "{:?} link base ({}) is not the attested version",
+                    link_type, base_address
+                ))
+            }
+
+            valid!()
+        },
+    }
+}
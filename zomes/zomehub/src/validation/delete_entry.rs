@@ -37,6 +37,26 @@ pub fn validation(
         EntryTypesUnit::ZomePackageVersion => {
             valid!()
         },
+        EntryTypesUnit::Review => {
+            if delete.author != create.author {
+                invalid!(format!(
+                    "Not authorized to delete review created by author {}",
+                    create.author
+                ))
+            }
+
+            valid!()
+        },
+        EntryTypesUnit::RebuildAttestation => {
+            if delete.author != create.author {
+                invalid!(format!(
+                    "Not authorized to delete rebuild attestation created by author {}",
+                    create.author
+                ))
+            }
+
+            valid!()
+        },
         // entry_type_unit => invalid!(format!("Delete validation not implemented for entry type: {:?}", entry_type_unit )),
     }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    hdi,
+    hdi_extensions,
+    LinkTypes,
+
+    ZomePackageVersionEntry,
+    validation::{
+        check_authority,
+        check_zome_package_authority,
+    },
+};
+
+use hdi::prelude::*;
+use hdi_extensions::{
+    summon_app_entry,
+    AnyLinkableHashTransformer,
+    // Macros
+    valid, invalid,
+};
+use coop_content_types::{
+    GroupEntry,
+};
+
+
+pub fn validation(
+    original_action_hash: ActionHash,
+    _base_address: AnyLinkableHash,
+    delete: DeleteLink,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record( original_action_hash )?;
+    let create_link = match record.action() {
+        Action::CreateLink(action) => action,
+        _ => invalid!(format!("Original action hash does not belong to create link action")),
+    };
+    let link_type = match LinkTypes::from_type( create_link.zome_index, create_link.link_type )? {
+        Some(lt) => lt,
+        None => invalid!(format!("No match for LinkTypes")),
+    };
+
+    // The link author can always remove their own links
+    if create_link.author == delete.author {
+        valid!()
+    }
+
+    match link_type {
+        LinkTypes::ZomePackage |
+        LinkTypes::NameToZomePackage |
+        LinkTypes::TagToZomePackage |
+        LinkTypes::NameIndexToZomePackage => {
+            if let ValidateCallbackResult::Invalid(msg) = check_zome_package_authority(
+                &create_link.target_address.must_be_action_hash()?, &delete.author, &delete.prev_action,
+            )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::ZomePackageToZomePackageVersion => {
+            let zome_package_version = ZomePackageVersionEntry::try_from(
+                must_get_valid_record( create_link.target_address.must_be_action_hash()? )?
+            )?;
+
+            if let ValidateCallbackResult::Invalid(msg) = check_authority( &zome_package_version.maintainer, &delete.author )? {
+                invalid!(msg)
+            }
+
+            valid!()
+        },
+        LinkTypes::NameToGroup |
+        LinkTypes::AllOrgsToGroup => {
+            // Agent based links are personal bookmarks; only the author can remove them
+            if create_link.base_address.clone().into_agent_pub_key().is_some() {
+                invalid!(format!(
+                    "Not authorized to delete link created by author {}",
+                    create_link.author
+                ))
+            }
+
+            let group : GroupEntry = summon_app_entry(
+                &create_link.target_address.must_be_action_hash()?.into()
+            )?;
+
+            if !group.is_contributor( &delete.author ) {
+                invalid!(format!(
+                    "Not authorized to delete link; {} is not a contributor of group {}",
+                    delete.author, create_link.target_address,
+                ))
+            }
+
+            valid!()
+        },
+        LinkTypes::AgentToZome |
+        LinkTypes::AgentToZomePackage |
+        LinkTypes::AgentToZomePackageVersion |
+        LinkTypes::AllAgentsToAgent |
+        LinkTypes::ZomePackageVersionToDependent |
+        LinkTypes::WasmHashToZome |
+        LinkTypes::ZomePackageVersionToReview |
+        LinkTypes::AgentToReview |
+        LinkTypes::ZomePackageVersionToRebuildAttestation => {
+            invalid!(format!(
+                "Not authorized to delete link created by author {}",
+                create_link.author
+            ))
+        },
+    }
+}
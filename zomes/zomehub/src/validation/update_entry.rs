@@ -3,9 +3,9 @@ use crate::{
     hdi_extensions,
     EntryTypes,
 
-    Authority,
     ZomePackageEntry,
     ZomePackageVersionEntry,
+    ReviewEntry,
     validation::{
         check_authority,
     },
@@ -16,6 +16,10 @@ use hdi_extensions::{
     // Macros
     valid, invalid,
 };
+use devhub_types::{
+    check_maintainer_unchanged,
+    check_review,
+};
 
 
 pub fn validation(
@@ -35,35 +39,10 @@ pub fn validation(
             //
             // Check if new maintainer is valid
             //
-            match ( &previous_entry.maintainer, &entry.maintainer ) {
-                (
-                    Authority::Group(prev_group_id, _),
-                    Authority::Group(group_id, _),
-                ) => {
-                    if prev_group_id != group_id {
-                        invalid!(format!(
-                            "The maintainer group cannot be changed: {} => {}",
-                            prev_group_id, group_id,
-                        ))
-                    }
-                },
-                (
-                    Authority::Agent(prev_agent_pubkey),
-                    Authority::Agent(agent_pubkey),
-                ) => {
-                    if prev_agent_pubkey != agent_pubkey {
-                        invalid!(format!(
-                            "The maintainer agent cannot be changed: {} => {}",
-                            prev_agent_pubkey, agent_pubkey,
-                        ))
-                    }
-                },
-                (expected_maintainer, maintainer) => {
-                    invalid!(format!(
-                        "Maintainer type cannot be changed: {:?} => {:?}",
-                        expected_maintainer, maintainer,
-                    ))
-                },
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
             }
 
             //
@@ -73,6 +52,12 @@ pub fn validation(
                 invalid!(msg)
             }
 
+            if entry.deprecation.is_some() && previous_entry.deprecation.is_some() {
+                invalid!(format!(
+                    "Cannot update deprecated entity unless the deprecation is being reversed",
+                ))
+            }
+
             valid!()
         },
         EntryTypes::ZomePackageVersion(entry) => {
@@ -82,35 +67,10 @@ pub fn validation(
             //
             // Check if new maintainer is valid
             //
-            match ( &previous_entry.maintainer, &entry.maintainer ) {
-                (
-                    Authority::Group(prev_group_id, _),
-                    Authority::Group(group_id, _),
-                ) => {
-                    if prev_group_id != group_id {
-                        invalid!(format!(
-                            "The maintainer group cannot be changed: {} => {}",
-                            prev_group_id, group_id,
-                        ))
-                    }
-                },
-                (
-                    Authority::Agent(prev_agent_pubkey),
-                    Authority::Agent(agent_pubkey),
-                ) => {
-                    if prev_agent_pubkey != agent_pubkey {
-                        invalid!(format!(
-                            "The maintainer agent cannot be changed: {} => {}",
-                            prev_agent_pubkey, agent_pubkey,
-                        ))
-                    }
-                },
-                (expected_maintainer, maintainer) => {
-                    invalid!(format!(
-                        "Maintainer type cannot be changed: {:?} => {:?}",
-                        expected_maintainer, maintainer,
-                    ))
-                },
+            if let ValidateCallbackResult::Invalid(msg) = check_maintainer_unchanged(
+                &previous_entry.maintainer, &entry.maintainer,
+            )? {
+                invalid!(msg)
             }
 
             //
@@ -120,8 +80,60 @@ pub fn validation(
                 invalid!(msg)
             }
 
+            //
+            // Check that the release contents are unchanged
+            //
+            if entry.for_package != previous_entry.for_package {
+                invalid!(format!(
+                    "The package of a version cannot be changed: {} => {}",
+                    previous_entry.for_package, entry.for_package,
+                ))
+            }
+
+            if entry.zome_entry != previous_entry.zome_entry {
+                invalid!(format!(
+                    "The zome entry of a version cannot be changed: {} => {}",
+                    previous_entry.zome_entry, entry.zome_entry,
+                ))
+            }
+
+            if entry.dependencies != previous_entry.dependencies {
+                invalid!(format!(
+                    "The dependencies of a version cannot be changed",
+                ))
+            }
+
+            if entry.yanked.is_some() && previous_entry.yanked.is_some() {
+                invalid!(format!(
+                    "Cannot update yanked entity unless the yank is being reversed",
+                ))
+            }
+
             valid!()
         },
+        EntryTypes::Review(entry) => {
+            let previous_entry : ReviewEntry = must_get_entry( original_entry_hash )?
+                .try_into()?;
+
+            if entry.reviewer != previous_entry.reviewer {
+                invalid!(format!(
+                    "The reviewer of a review cannot be changed: {} => {}",
+                    previous_entry.reviewer, entry.reviewer,
+                ))
+            }
+
+            if entry.for_version != previous_entry.for_version {
+                invalid!(format!(
+                    "The version of a review cannot be changed: {} => {}",
+                    previous_entry.for_version, entry.for_version,
+                ))
+            }
+
+            check_review( &entry, &update.author )
+        },
+        EntryTypes::RebuildAttestation(_) => {
+            invalid!(format!("RebuildAttestationEntry are not intended to be updated"))
+        },
         // _ => invalid!(format!("Update validation not implemented for entry type: {:#?}", update.entry_type )),
     }
 }
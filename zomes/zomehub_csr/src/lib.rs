@@ -3,6 +3,8 @@ mod zome_handlers;
 mod zome_package_handlers;
 mod zome_package_version_handlers;
 mod zome_package_base;
+mod review_handlers;
+mod rebuild_attestation_handlers;
 
 pub use zomehub::hdi;
 pub use zomehub::hdi_extensions;
@@ -21,10 +23,15 @@ use zomehub::{
 };
 use zomehub_sdk::{
     LinkBase,
+    NameIndex,
+    devhub_types::{
+        DevHubProperties,
+    },
 };
 
 
 pub type TypedLinkBase = LinkBase<LinkTypes>;
+pub type TypedNameIndex = NameIndex<LinkTypes>;
 
 lazy_static! {
     pub static ref AGENT_ID : AgentPubKey = agent_id().expect("Unable to obtain current Agent context");
@@ -44,8 +51,15 @@ lazy_static! {
         .expect("Unable to derive all_agents anchor");
     pub static ref ALL_ZOME_PACKS_ANCHOR : TypedLinkBase = LinkBase::new( ALL_ZOME_PACKS_ANCHOR_HASH.clone(), LinkTypes::ZomePackage );
 
+    pub static ref DEPRECATED_ZOME_PACKS_ANCHOR_HASH : EntryHash = Path::from( vec![ Component::from("deprecated_zome_packages".as_bytes().to_vec()) ] )
+        .path_entry_hash()
+        .expect("Unable to derive deprecated_zome_packages anchor");
+    pub static ref DEPRECATED_ZOME_PACKS_ANCHOR : TypedLinkBase = LinkBase::new( DEPRECATED_ZOME_PACKS_ANCHOR_HASH.clone(), LinkTypes::ZomePackage );
+
     pub static ref MY_ZOMES_ANCHOR : TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToZome );
     pub static ref MY_ZOME_PACKS_ANCHOR : TypedLinkBase = LinkBase::new( AGENT_ID.clone(), LinkTypes::AgentToZomePackage );
+
+    pub static ref ZOME_PACKS_NAME_INDEX : TypedNameIndex = NameIndex::new( LinkTypes::NameIndexToZomePackage );
 }
 
 
@@ -55,6 +69,15 @@ fn init(_: ()) -> ExternResult<InitCallbackResult> {
     let zome_name = zome_settings.name;
     debug!("'{}' init", zome_name );
 
+    // Every upload would fail validation with malformed DNA properties
+    let properties = match DevHubProperties::get() {
+        Ok(properties) => properties,
+        Err(err) => return Ok(InitCallbackResult::Fail(format!(
+            "Invalid DNA properties: {:?}", err,
+        ))),
+    };
+    debug!("Storage quota: {:?}", properties.storage_quota );
+
     let main_functions : Vec<(&str, &str)> = zome_settings.extern_fns.iter()
         .filter_map(|fn_name| match fn_name.as_ref().starts_with("get_") {
             true => Some(( zome_name.0.as_ref(), fn_name.0.as_str() )),
@@ -0,0 +1,64 @@
+use crate::{
+    hdk,
+};
+
+use hdk::prelude::*;
+use zomehub::{
+    EntryTypes,
+    LinkTypes,
+
+    ZomeEntry,
+    ZomePackageVersionEntry,
+    RebuildAttestationEntry,
+    hc_crud::{
+        Entity, EntityId,
+    },
+};
+use zomehub_sdk::{
+    RebuildAttestationLinks,
+    SubmitRebuildAttestationInput,
+};
+
+
+fn attestations() -> RebuildAttestationLinks<EntryTypes, LinkTypes> {
+    RebuildAttestationLinks::new( LinkTypes::ZomePackageVersionToRebuildAttestation )
+}
+
+
+#[hdk_extern]
+fn submit_rebuild_attestation(input: SubmitRebuildAttestationInput) ->
+    ExternResult<Entity<RebuildAttestationEntry>>
+{
+    attestations().submit_rebuild_attestation( input )
+}
+
+
+#[hdk_extern]
+fn get_rebuild_attestation(addr: EntityId) -> ExternResult<Entity<RebuildAttestationEntry>> {
+    attestations().get_rebuild_attestation( &addr )
+}
+
+
+#[hdk_extern]
+fn get_rebuild_attestations_for_version(version_id: EntityId) ->
+    ExternResult<Vec<Entity<RebuildAttestationEntry>>>
+{
+    attestations().get_rebuild_attestations_for_version( &version_id )
+}
+
+
+/// Count the attestations whose hash matches the version's `ZomeEntry.hash`
+pub fn count_matching_rebuild_attestations(
+    version_id: &EntityId,
+    version: &ZomePackageVersionEntry,
+) -> ExternResult<u64> {
+    let zome_entry : ZomeEntry = must_get_entry( version.zome_entry.clone() )?.content.try_into()?;
+
+    attestations().count_matching_rebuild_attestations( version_id, &zome_entry.hash )
+}
+
+
+#[hdk_extern]
+fn delete_rebuild_attestation(id: EntityId) -> ExternResult<ActionHash> {
+    attestations().delete_rebuild_attestation( &id )
+}
@@ -2,6 +2,7 @@ use crate::{
     hdk,
     hdk_extensions,
     MY_ZOMES_ANCHOR,
+    TypedLinkBase,
 };
 use hdk::prelude::*;
 use hdk_extensions::{
@@ -12,10 +13,11 @@ use hdk_extensions::{
 };
 use zomehub::{
     EntryTypes,
+    EntryTypesUnit,
     LinkTypes,
 
-    ZomeType,
     ZomeEntry,
+    wasm_hash_anchor_path,
     hc_crud::{
         Entity,
         EntryModel,
@@ -25,31 +27,78 @@ use zomehub::{
 use zomehub_sdk::{
     LinkBase,
     ZomeAsset,
+    CreateZomeEntryInput,
+    IngestedZome,
+    inspect_wasm_exports,
+    StorageUsage,
+    Page,
+    PaginatedInput,
 };
 
 
+fn wasm_hash_anchor(hash: &str) -> ExternResult<TypedLinkBase> {
+    let anchor_path = wasm_hash_anchor_path( hash ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::WasmHashToZome ) )
+}
+
+
+fn create_zome_entry_handler(input: ZomeEntry) -> ExternResult<Entity<ZomeEntry>> {
+    // Identical wasm is reused instead of creating a duplicate entry
+    let existing = get_zome_entries_by_hash( input.hash.clone() )?.into_iter()
+        .find( |entity| {
+            entity.content.zome_type == input.zome_type
+                && entity.content.file_size == input.file_size
+        });
+
+    if let Some(entity) = existing {
+        MY_ZOMES_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+
+        return Ok( entity );
+    }
 
-#[hdk_extern]
-fn create_zome_entry(input: ZomeEntry) -> ExternResult<Entity<ZomeEntry>> {
     let entity = create_entity( &input )?;
 
     MY_ZOMES_ANCHOR.create_link_if_not_exists( &entity.address, () )?;
+    wasm_hash_anchor( &input.hash )?.create_link_if_not_exists( &entity.id, () )?;
 
     Ok( entity )
 }
 
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct CreateZomeEntryInput {
-    pub zome_type: ZomeType,
-    pub mere_memory_addr: EntryHash,
+/// Create a zome entry; the given exports are replaced by the ones read from the wasm
+///
+/// Validation does not reassemble the wasm, so the exports (and the detected zome type) are
+/// only ever written from the mere_memory bytes.
+#[hdk_extern]
+fn create_zome_entry(input: ZomeEntry) -> ExternResult<Entity<ZomeEntry>> {
+    let exports = inspect_wasm_exports( &input.mere_memory_addr )?;
+
+    create_zome_entry_handler( input.with_exports( exports ) )
 }
 
+
 #[hdk_extern]
 fn create_zome(input: CreateZomeEntryInput) -> ExternResult<Entity<ZomeEntry>> {
-    let entry = ZomeEntry::new( input.zome_type, input.mere_memory_addr )?;
+    let exports = inspect_wasm_exports( &input.mere_memory_addr )?;
+    let entry = ZomeEntry::new( input.zome_type, input.mere_memory_addr )?
+        .with_exports( exports );
+
+    create_zome_entry_handler( entry )
+}
+
 
-    create_zome_entry( entry )
+/// Create a zome for WASM already saved in mere_memory and return it with this DNA's hash
+///
+/// Used when unpacking bundles in another cell, which needs the ZomeHub DNA hash to build an HRL.
+#[hdk_extern]
+fn ingest_zome(input: CreateZomeEntryInput) -> ExternResult<IngestedZome> {
+    Ok(
+        IngestedZome {
+            dna: dna_info()?.hash,
+            zome: create_zome( input )?,
+        }
+    )
 }
 
 
@@ -72,6 +121,20 @@ fn get_zome_entry(addr: AnyDhtHash) -> ExternResult<Entity<ZomeEntry>> {
 }
 
 
+/// Get the zome entries for a wasm hash (`MemoryEntry.hash`)
+#[hdk_extern]
+fn get_zome_entries_by_hash(hash: String) -> ExternResult<Vec<Entity<ZomeEntry>>> {
+    let zomes = wasm_hash_anchor( &hash )?.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let id = link.target.into_action_hash()?;
+            get_zome_entry( id.into() ).ok()
+        })
+        .collect();
+
+    Ok( zomes )
+}
+
+
 #[hdk_extern]
 fn get_zome_asset(addr: EntryHash) -> ExternResult<ZomeAsset> {
     Ok( addr.try_into()? )
@@ -99,7 +162,48 @@ fn get_zome_entries_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
 }
 
 
+#[hdk_extern]
+fn get_zome_entries_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<ZomeEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToZome );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_entry_hash()?;
+                get_zome_entry( addr.into() ).ok()
+            })
+    )
+}
+
+
+/// Get the total size of the zomes created by an agent and the storage quota of this DNA
+#[hdk_extern]
+fn get_agent_storage_usage(maybe_agent_id: Option<AgentPubKey>) -> ExternResult<StorageUsage> {
+    let agent_id = match maybe_agent_id {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+
+    zomehub_sdk::get_agent_storage_usage(
+        agent_id,
+        EntryTypesUnit::Zome.try_into()?,
+        |entry| Ok( ZomeEntry::try_from( entry )?.file_size ),
+    )
+}
+
+
 #[hdk_extern]
 fn delete_zome(addr: ActionHash) -> ExternResult<ActionHash> {
-    Ok( delete_entity::<ZomeEntry,EntryTypes>( &addr )? )
+    let zome_entry = get_zome_entry( addr.clone().into() )?.content;
+    let delete_addr = delete_entity::<ZomeEntry,EntryTypes>( &addr )?;
+
+    wasm_hash_anchor( &zome_entry.hash )?.delete_all_my_links_to_target( &addr, None )?;
+
+    Ok( delete_addr )
 }
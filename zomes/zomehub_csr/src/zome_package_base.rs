@@ -1,125 +1,116 @@
-use crate::hdk;
-
-use std::{
-    str,
-    collections::BTreeMap,
+use crate::{
+    hdk,
 };
+
+use std::ops::Deref;
 use hdk::prelude::*;
+use semver::{
+    Version,
+    VersionReq,
+};
 use zomehub::{
     LinkTypes,
+    ZomePackageVersionEntry,
     hc_crud::{
-        EntityId,
+        Entity, EntityId,
     },
 };
 use zomehub_sdk::{
-    LinkBase,
-    EntityPointerMap,
+    PackageBase,
+    Page,
+    PageQuery,
     ZomePackageVersionMap,
 };
-use zomehub_sdk::{
-    create_link_input,
-};
 
 
-pub struct ZomePackageBase(pub EntityId);
+pub struct ZomePackageBase(pub PackageBase<LinkTypes>);
 
-impl ZomePackageBase {
-    pub fn new(id: &EntityId) -> Self {
-        Self( id.to_owned() )
-    }
+impl Deref for ZomePackageBase {
+    type Target = PackageBase<LinkTypes>;
 
-    pub fn id(&self) -> EntityId {
-        self.0.to_owned()
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
+}
 
-    pub fn version_link_base(&self) -> LinkBase<LinkTypes> {
-        LinkBase::new( self.id(), LinkTypes::ZomePackageToZomePackageVersion )
+impl ZomePackageBase {
+    pub fn new(id: &EntityId) -> Self {
+        Self( PackageBase::new( id, LinkTypes::ZomePackageToZomePackageVersion ) )
     }
 
-    pub fn create_version_link(&self, version_id: &ActionHash, version_name: &str ) -> ExternResult<ActionHash> {
-        let tag = version_name.as_bytes().to_vec();
-        let versions_base = self.version_link_base();
+    /// Versions that have not been yanked
+    pub fn versions(&self) -> ExternResult<ZomePackageVersionMap> {
+        let mut version_map = self.versions_including_yanked()?;
 
-        Ok(
-            match versions_base.links_exist( version_id, tag )? {
-                Some(link) => link.create_link_hash,
-                None => versions_base.create_link(
-                    version_id, version_name.as_bytes().to_vec()
-                )?,
+        version_map.retain( |vname, version| {
+            if version.content.yanked.is_some() {
+                debug!("Skipping version '{}' because it was yanked", vname );
+                return false;
             }
-        )
-    }
+            true
+        });
 
-    pub fn version_links(&self) -> ExternResult<Vec<Link>> {
-        get_links(
-            create_link_input(
-                &self.id(),
-                &LinkTypes::ZomePackageToZomePackageVersion,
-                &None::<()>,
-            )?
-        )
+        Ok( version_map )
     }
 
-    pub fn links_for_version(&self, version: &str ) -> ExternResult<Vec<Link>> {
-        Ok(
-            self.version_links()?.into_iter()
-                .filter_map(|link| {
-                    let tag = str::from_utf8( &link.tag.0 )
-                        .map( |value| value.to_string() )
-                        .map_err( |err| {
-                            debug!("Failed to parse version from tag {:?}: {:#?}", link.tag, err );
-                        }).ok()?;
-
-                    match tag == version {
-                        true => Some( link ),
-                        false => None,
-                    }
-                })
-                .collect()
-        )
+    pub fn versions_including_yanked(&self) -> ExternResult<ZomePackageVersionMap> {
+        self.0.versions( crate::zome_package_version_handlers::get_zome_package_version_entity )
     }
 
-    pub fn version_targets(&self) -> ExternResult<EntityPointerMap> {
-        let version_links = self.version_links()?.iter()
-            .filter_map(|link| {
-                if let Some(target) = link.target.clone().into_action_hash() {
-                    Some(( link, target ))
-                } else {
-                    debug!("Skipping link target because it is not an ActionHash; {:#?}", link.target );
-                    None
-                }
-            })
-            .filter_map(|(link, target)| {
-                Some((
-                    str::from_utf8( &link.tag.0 )
-                        .map( |value| value.to_string() )
-                        .map_err( |err| {
-                            debug!("Failed to parse version from tag {:?}: {:#?}", link.tag, err );
-                        }).ok()?,
-                    target,
-                ))
-            })
-            .collect();
-
-        Ok( version_links )
+    /// Get the highest non-yanked version that satisfies the given requirement
+    pub fn latest_version_matching(&self, requirement: &VersionReq) ->
+        ExternResult<Option<(String, Entity<ZomePackageVersionEntry>)>>
+    {
+        let candidates = self.semver_targets()?.into_iter()
+            .filter( |(version, _)| requirement.matches( version ) );
+
+        Ok( Self::first_available( candidates ) )
     }
 
-    pub fn versions(&self) -> ExternResult<ZomePackageVersionMap> {
-        let version_targets = self.version_targets()?;
-        let mut version_map = BTreeMap::new();
+    /// Get the highest non-yanked stable version, or the highest pre-release when there is no stable
+    /// version
+    pub fn latest_version(&self) ->
+        ExternResult<Option<(String, Entity<ZomePackageVersionEntry>)>>
+    {
+        let (stable, pre_release) : (Vec<_>, Vec<_>) = self.semver_targets()?.into_iter()
+            .partition( |(version, _)| version.pre.is_empty() );
 
-        for (vname, version_id) in version_targets.into_iter() {
-            debug!("Get Zome package version: {}", version_id );
-            let version = match crate::zome_package_version_handlers::get_zome_package_version( version_id ) {
-                Ok(value) => value,
+        Ok( Self::first_available( stable.into_iter().chain( pre_release ) ) )
+    }
+
+    fn first_available<I>(candidates: I) -> Option<(String, Entity<ZomePackageVersionEntry>)>
+    where
+        I: IntoIterator<Item = (Version, EntityId)>,
+    {
+        for (version, version_id) in candidates {
+            match crate::zome_package_version_handlers::get_zome_package_version_entity( version_id ) {
+                Ok(entity) if entity.content.yanked.is_some() => {
+                    debug!("Skipping version '{}' because it was yanked", version );
+                },
+                Ok(entity) => return Some(( version.to_string(), entity )),
                 Err(err) => {
-                    debug!("Dropping version '{}' because of failure to get version info: {:#?}", vname, err );
-                    continue;
+                    debug!("Skipping version '{}' because of failure to get version info: {:#?}", version, err );
                 },
-            };
-            version_map.insert( vname, version );
+            }
         }
 
-        Ok( version_map )
+        None
+    }
+
+    /// A page of versions in link order; yanked versions are skipped
+    pub fn versions_page(&self, query: &PageQuery) ->
+        ExternResult<Page<(String, Entity<ZomePackageVersionEntry>)>>
+    {
+        Ok(
+            self.0.versions_page( query, crate::zome_package_version_handlers::get_zome_package_version_entity )?
+                .filter_map(|(vname, version)| {
+                    if version.content.yanked.is_some() {
+                        debug!("Skipping version '{}' because it was yanked", vname );
+                        return None;
+                    }
+
+                    Some(( vname, version ))
+                })
+        )
     }
 }
@@ -1,11 +1,17 @@
 use crate::{
     hdk,
     hdk_extensions,
+    TypedLinkBase,
     MY_ZOME_PACKS_ANCHOR,
     ALL_ZOME_PACKS_ANCHOR,
+    DEPRECATED_ZOME_PACKS_ANCHOR,
+    ZOME_PACKS_NAME_INDEX,
 };
 
-use std::collections::BTreeMap;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
 use hdk::prelude::*;
 use hdk_extensions::{
     must_get,
@@ -20,8 +26,11 @@ use zomehub::{
     LinkTypes,
     RmpvValue,
     Authority,
+    ZomeType,
 
     ZomePackageEntry,
+    DeprecationNotice,
+    tag_anchor_path,
     hc_crud::{
         Entity, EntityId,
         EntryModel,
@@ -31,13 +40,32 @@ use zomehub::{
 };
 use zomehub_sdk::{
     LinkBase,
+    normalize_name,
+    ZomePackageRef,
     CreateZomePackageInput,
+    Page,
+    PaginatedInput,
+    get_links_page_from_bases,
 };
 use coop_content_sdk::{
     get_group_content_latest,
 };
 
 
+fn normalize_tags(tags: &Option<Vec<String>>) -> BTreeSet<String> {
+    tags.to_owned().unwrap_or_default().iter()
+        .map( |tag| tag.trim().to_lowercase() )
+        .filter( |tag| !tag.is_empty() )
+        .collect()
+}
+
+fn tag_anchor(tag: &str) -> ExternResult<TypedLinkBase> {
+    let anchor_path = tag_anchor_path( tag ).path_entry_hash()?;
+
+    Ok( LinkBase::new( anchor_path, LinkTypes::TagToZomePackage ) )
+}
+
+
 #[hdk_extern]
 fn create_zome_package_entry(input: ZomePackageEntry) -> ExternResult<Entity<ZomePackageEntry>> {
     let entity = create_entity( &input )?;
@@ -49,6 +77,12 @@ fn create_zome_package_entry(input: ZomePackageEntry) -> ExternResult<Entity<Zom
     let name_anchor = LinkBase::new( anchor_path, LinkTypes::NameToZomePackage );
     name_anchor.create_link_if_not_exists( &entity.id, () )?;
 
+    ZOME_PACKS_NAME_INDEX.add( &input.name, &entity.id )?;
+
+    for tag in normalize_tags( &input.tags ) {
+        tag_anchor( &tag )?.create_link_if_not_exists( &entity.id, tag.as_bytes().to_vec() )?;
+    }
+
     Ok( entity )
 }
 
@@ -75,6 +109,7 @@ pub type UpdateInput = UpdateEntityInput<UpdateProperties>;
 pub fn update_zome_package(input: UpdateInput) -> ExternResult<Entity<ZomePackageEntry>> {
     debug!("Updating zome package: {}", input.base );
     let props = input.properties.clone();
+    let previous_tags = normalize_tags( &get_zome_package_entry( input.base.clone().into() )?.content.tags );
 
     let entity = update_entity(
 	&input.base,
@@ -93,6 +128,105 @@ pub fn update_zome_package(input: UpdateInput) -> ExternResult<Entity<ZomePackag
 	    Ok( current )
 	})?;
 
+    let current_tags = normalize_tags( &entity.content.tags );
+
+    for tag in previous_tags.difference( &current_tags ) {
+        let anchor = tag_anchor( tag )?;
+        let link_tag = Some( tag.as_bytes().to_vec().into() );
+
+        match entity.content.maintainer {
+            // Any group contributor may have created the link
+            Authority::Group(..) => anchor.delete_all_links_to_target( &entity.id, link_tag )?,
+            Authority::Agent(_) => anchor.delete_all_my_links_to_target( &entity.id, link_tag )?,
+        };
+    }
+
+    for tag in current_tags.difference( &previous_tags ) {
+        tag_anchor( tag )?.create_link_if_not_exists( &entity.id, tag.as_bytes().to_vec() )?;
+    }
+
+    Ok( entity )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeprecateZomePackageInput {
+    pub message: String,
+    #[serde(default)]
+    pub recommended_alternatives: Vec<ActionHash>,
+    pub maintainer: Option<Authority>,
+}
+
+#[hdk_extern]
+pub fn deprecate_zome_package(input: UpdateEntityInput<DeprecateZomePackageInput>) ->
+    ExternResult<Entity<ZomePackageEntry>>
+{
+    let props = input.properties;
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : ZomePackageEntry, _| {
+	    if current.deprecation.is_some() {
+		return Err(guest_error!(format!(
+		    "Zome package ({}) is already deprecated", input.base,
+		)));
+	    }
+
+	    current.deprecation = Some(DeprecationNotice {
+		message: props.message.clone(),
+		recommended_alternatives: props.recommended_alternatives.clone(),
+	    });
+	    current.maintainer = props.maintainer.clone()
+		.unwrap_or( current.maintainer );
+
+	    Ok( current )
+	})?;
+
+    // Move the package to the deprecated anchor so that listing active packages is one get_links
+    match entity.content.maintainer {
+        // Any group contributor may have created the link
+        Authority::Group(..) => ALL_ZOME_PACKS_ANCHOR.delete_all_links_to_target( &entity.id, None )?,
+        Authority::Agent(_) => ALL_ZOME_PACKS_ANCHOR.delete_all_my_links_to_target( &entity.id, None )?,
+    };
+    DEPRECATED_ZOME_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, entity.content.name.clone() )?;
+
+    Ok( entity )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UndeprecateZomePackageInput {
+    pub maintainer: Option<Authority>,
+}
+
+#[hdk_extern]
+pub fn undeprecate_zome_package(input: UpdateEntityInput<UndeprecateZomePackageInput>) ->
+    ExternResult<Entity<ZomePackageEntry>>
+{
+    let props = input.properties;
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : ZomePackageEntry, _| {
+	    if current.deprecation.is_none() {
+		return Err(guest_error!(format!(
+		    "Zome package ({}) is not deprecated", input.base,
+		)));
+	    }
+
+	    current.deprecation = None;
+	    current.maintainer = props.maintainer.clone()
+		.unwrap_or( current.maintainer );
+
+	    Ok( current )
+	})?;
+
+    match entity.content.maintainer {
+        Authority::Group(..) => DEPRECATED_ZOME_PACKS_ANCHOR.delete_all_links_to_target( &entity.id, None )?,
+        Authority::Agent(_) => DEPRECATED_ZOME_PACKS_ANCHOR.delete_all_my_links_to_target( &entity.id, None )?,
+    };
+    ALL_ZOME_PACKS_ANCHOR.create_link_if_not_exists( &entity.id, entity.content.name.clone() )?;
+
     Ok( entity )
 }
 
@@ -148,10 +282,123 @@ pub fn get_zome_package(addr: EntityId) -> ExternResult<Entity<ZomePackageEntry>
 
 
 #[hdk_extern]
-fn get_all_zome_package_links() ->
+fn get_all_zome_package_links(include_deprecated: Option<bool>) ->
     ExternResult<Vec<Link>>
 {
-    Ok(ALL_ZOME_PACKS_ANCHOR.get_links( None )?)
+    let mut links = ALL_ZOME_PACKS_ANCHOR.get_links( None )?;
+
+    if include_deprecated.unwrap_or(false) {
+        links.extend( DEPRECATED_ZOME_PACKS_ANCHOR.get_links( None )? );
+    }
+
+    Ok( links )
+}
+
+
+#[hdk_extern]
+fn get_all_zome_packages_paginated(input: PaginatedInput<Option<bool>>) ->
+    ExternResult<Page<Entity<ZomePackageEntry>>>
+{
+    // Deprecated packages are moved from the global anchor to the deprecated anchor
+    let links_page = match input.input.unwrap_or(false) {
+        true => get_links_page_from_bases(
+            &[ &ALL_ZOME_PACKS_ANCHOR, &DEPRECATED_ZOME_PACKS_ANCHOR ], None, &input.page
+        )?,
+        false => ALL_ZOME_PACKS_ANCHOR.get_links_page( None, &input.page )?,
+    };
+
+    Ok(
+        links_page
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_zome_package( addr ).ok()
+            })
+    )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchZomePackagesInput {
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub name_prefix: Option<String>,
+    pub zome_type: Option<ZomeType>,
+    #[serde(default)]
+    pub include_deprecated: bool,
+}
+
+/// Search zome packages by tags, name prefix and zome type
+///
+/// Pages follow the links of the first requested tag's anchor (or the global and deprecated
+/// anchors when no tags are given) and the remaining criteria are checked against the latest
+/// package entry, so a page may hold fewer items than the limit.  The name prefix is matched
+/// case-insensitively.
+#[hdk_extern]
+pub fn search_zome_packages(input: PaginatedInput<SearchZomePackagesInput>) ->
+    ExternResult<Page<Entity<ZomePackageEntry>>>
+{
+    let criteria = input.input;
+    let tags = normalize_tags( &Some(criteria.tags.clone()) );
+
+    let name_prefix = criteria.name_prefix.as_ref()
+        .map( |prefix| prefix.to_lowercase() );
+
+    // Deprecated packages are moved from the global anchor to the deprecated anchor
+    let links_page = match tags.iter().next() {
+        Some(tag) => tag_anchor( tag )?.get_links_page( None, &input.page )?,
+        None if criteria.include_deprecated => get_links_page_from_bases(
+            &[ &ALL_ZOME_PACKS_ANCHOR, &DEPRECATED_ZOME_PACKS_ANCHOR ], None, &input.page
+        )?,
+        None => ALL_ZOME_PACKS_ANCHOR.get_links_page( None, &input.page )?,
+    };
+
+    Ok(
+        links_page
+            .filter_map( |link| get_zome_package( link.target.into_action_hash()? ).ok() )
+            .filter_map( |entity| {
+                let package = &entity.content;
+
+                // Tags can be changed by updates so the latest entry is the source of truth
+                let matches = tags.is_subset( &normalize_tags( &package.tags ) )
+                    && name_prefix.as_ref()
+                        .is_none_or( |prefix| package.name.to_lowercase().starts_with( prefix.as_str() ) )
+                    && criteria.zome_type.as_ref()
+                        .is_none_or( |zome_type| &package.zome_type == zome_type )
+                    && ( criteria.include_deprecated || package.deprecation.is_none() );
+
+                match matches {
+                    true => Some( entity ),
+                    false => None,
+                }
+            })
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_zome_packages_by_name_prefix(prefix: String) ->
+    ExternResult<Vec<Entity<ZomePackageEntry>>>
+{
+    let prefix = normalize_name( &prefix );
+
+    Ok(
+        ZOME_PACKS_NAME_INDEX.search( &prefix )?.into_iter()
+            .filter_map( |link| get_zome_package( link.target.into_action_hash()? ).ok() )
+            .filter( |entity| normalize_name( &entity.content.name ).starts_with( &prefix ) )
+            .collect()
+    )
+}
+
+
+#[hdk_extern]
+pub fn search_zome_packages_by_name_fuzzy(query: String) ->
+    ExternResult<Vec<Entity<ZomePackageEntry>>>
+{
+    Ok(
+        ZOME_PACKS_NAME_INDEX.fuzzy_search( &query )?.into_iter()
+            .filter_map( |link| get_zome_package( link.target.into_action_hash()? ).ok() )
+            .collect()
+    )
 }
 
 
@@ -172,6 +419,14 @@ pub fn get_zome_package_by_name(name: String) -> ExternResult<Entity<ZomePackage
 }
 
 
+pub fn resolve_zome_package_id(package: ZomePackageRef) -> ExternResult<EntityId> {
+    Ok(match package {
+        ZomePackageRef::Id(id) => id,
+        ZomePackageRef::Name(name) => get_zome_package_by_name( name )?.id,
+    })
+}
+
+
 #[hdk_extern]
 fn get_zome_packages_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
     ExternResult<Vec<Entity<ZomePackageEntry>>>
@@ -193,6 +448,26 @@ fn get_zome_packages_for_agent(maybe_agent_id: Option<AgentPubKey>) ->
 }
 
 
+#[hdk_extern]
+fn get_zome_packages_for_agent_paginated(input: PaginatedInput<Option<AgentPubKey>>) ->
+    ExternResult<Page<Entity<ZomePackageEntry>>>
+{
+    let agent_id = match input.input {
+        Some(agent_id) => agent_id,
+        None => hdk_extensions::agent_id()?,
+    };
+    let agent_anchor = LinkBase::new( agent_id, LinkTypes::AgentToZomePackage );
+
+    Ok(
+        agent_anchor.get_links_page( None, &input.page )?
+            .filter_map(|link| {
+                let addr = link.target.into_action_hash()?;
+                get_zome_package( addr ).ok()
+            })
+    )
+}
+
+
 #[hdk_extern]
 pub fn delete_zome_package(id: EntityId) -> ExternResult<bool> {
     let zome_package = get_zome_package( id.clone() )?.content;
@@ -202,14 +477,26 @@ pub fn delete_zome_package(id: EntityId) -> ExternResult<bool> {
         debug!("Deleted 'my' zome links: {:?}", deleted_links );
     }
 
+    // Package links may have been created by any maintainer of the package
     {
-        let deleted_links = ALL_ZOME_PACKS_ANCHOR.delete_all_my_links_to_target( &id, None )?;
+        let deleted_links = ALL_ZOME_PACKS_ANCHOR.delete_all_links_to_target( &id, None )?;
         debug!("Deleted 'all' zome links: {:?}", deleted_links );
     }
 
+    {
+        let deleted_links = DEPRECATED_ZOME_PACKS_ANCHOR.delete_all_links_to_target( &id, None )?;
+        debug!("Deleted 'deprecated' zome links: {:?}", deleted_links );
+    }
+
     let anchor_path = Path::from( vec![ Component::from(zome_package.name.as_bytes().to_vec()) ] ).path_entry_hash()?;
     let name_anchor = LinkBase::new( anchor_path, LinkTypes::NameToZomePackage );
-    name_anchor.delete_all_my_links_to_target( &id, None )?;
+    name_anchor.delete_all_links_to_target( &id, None )?;
+
+    ZOME_PACKS_NAME_INDEX.remove_all( &zome_package.name, &id )?;
+
+    for tag in normalize_tags( &zome_package.tags ) {
+        tag_anchor( &tag )?.delete_all_links_to_target( &id, None )?;
+    }
 
     Ok(true)
 }
@@ -2,23 +2,41 @@ use crate::{
     hdk,
     hdk_extensions,
     ZomePackageBase,
+    zome_package_handlers::{
+        resolve_zome_package_id,
+    },
+    rebuild_attestation_handlers::{
+        count_matching_rebuild_attestations,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    VecDeque,
 };
-use std::collections::BTreeMap;
 
 use hdk::prelude::*;
 use hdk_extensions::{
     must_get,
     hdi_extensions::{
+        guest_error,
         trace_origin_root,
         ScopedTypeConnector,
     },
 };
+use semver::{
+    Version,
+    VersionReq,
+};
 use zomehub::{
-    // LinkTypes,
+    LinkTypes,
     RmpvValue,
     Authority,
     ApiCompatibility,
+    BuildProvenance,
+    YankNotice,
+    ZomeDependency,
 
+    ZomeEntry,
     ZomePackageVersionEntry,
     hc_crud::{
         Entity, EntityId,
@@ -28,10 +46,15 @@ use zomehub::{
     },
 };
 use zomehub_sdk::{
-    // LinkBase,
+    LinkBase,
     EntityPointerMap,
+    ZomePackageRef,
     ZomePackageVersionMap,
     CreateZomePackageVersionInput,
+    ResolvedZomeDependency,
+    AttestedVersion,
+    Page,
+    PaginatedInput,
 };
 use coop_content_sdk::{
     get_group_content_latest,
@@ -40,13 +63,33 @@ use coop_content_sdk::{
 
 
 #[hdk_extern]
-fn create_zome_package_version_entry(input: ZomePackageVersionEntry) ->
+fn create_zome_package_version_entry(mut input: ZomePackageVersionEntry) ->
     ExternResult<Entity<ZomePackageVersionEntry>>
 {
+    // Integrity checks the pinned version's tag through the link that claims it
+    for dependency in input.dependencies.iter_mut().flatten() {
+        if let (Some(version_id), None) = (&dependency.version, &dependency.version_link) {
+            let target : AnyLinkableHash = version_id.to_owned().into();
+
+            dependency.version_link = ZomePackageBase::new( &dependency.package )
+                .sorted_version_links()?.into_iter()
+                .find( |link| link.target == target )
+                .map( |link| link.create_link_hash );
+        }
+    }
+
     let entity = create_entity( &input )?;
 
     // TODO: Link from package
 
+    // Reverse links so that dependents can be found from each depended-on version
+    for dependency in input.dependencies.iter().flatten() {
+        if let Some(version_id) = &dependency.version {
+            LinkBase::new( version_id.clone(), LinkTypes::ZomePackageVersionToDependent )
+                .create_link_if_not_exists( &entity.id, dependency.name.as_bytes().to_vec() )?;
+        }
+    }
+
     Ok( entity )
 }
 
@@ -116,6 +159,66 @@ pub fn get_zome_package_versions(zome_package_id: EntityId) ->
 }
 
 
+#[hdk_extern]
+pub fn get_zome_package_versions_paginated(input: PaginatedInput<EntityId>) ->
+    ExternResult<Page<(String, Entity<ZomePackageVersionEntry>)>>
+{
+    let base = ZomePackageBase::new( &input.input );
+
+    base.versions_page( &input.page )
+}
+
+
+#[hdk_extern]
+pub fn get_zome_package_versions_including_yanked(zome_package_id: EntityId) ->
+    ExternResult<ZomePackageVersionMap>
+{
+    let base = ZomePackageBase::new( &zome_package_id );
+
+    base.versions_including_yanked()
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GetZomePackageVersionBySemverInput {
+    pub for_package: ZomePackageRef,
+    pub version_req: String,
+}
+
+#[hdk_extern]
+pub fn get_zome_package_version_by_semver(input: GetZomePackageVersionBySemverInput) ->
+    ExternResult<(String, Entity<ZomePackageVersionEntry>)>
+{
+    let requirement = VersionReq::parse( &input.version_req )
+        .map_err( |err| guest_error!(format!(
+            "Invalid semver requirement '{}': {}", input.version_req, err,
+        )) )?;
+    let zome_package_id = resolve_zome_package_id( input.for_package )?;
+    let base = ZomePackageBase::new( &zome_package_id );
+
+    base.latest_version_matching( &requirement )?
+        .ok_or(guest_error!(format!(
+            "No version of zome package ({}) matches requirement '{}'",
+            zome_package_id, input.version_req,
+        )))
+}
+
+
+#[hdk_extern]
+pub fn get_latest_zome_package_version(for_package: ZomePackageRef) ->
+    ExternResult<(String, Entity<ZomePackageVersionEntry>)>
+{
+    let zome_package_id = resolve_zome_package_id( for_package )?;
+    let base = ZomePackageBase::new( &zome_package_id );
+
+    base.latest_version()?
+        .ok_or(guest_error!(format!(
+            "No versions found for zome package ({})",
+            zome_package_id,
+        )))
+}
+
+
 #[hdk_extern]
 fn get_zome_package_version_entry(addr: AnyDhtHash) ->
     ExternResult<Entity<ZomePackageVersionEntry>>
@@ -136,8 +239,8 @@ fn get_zome_package_version_entry(addr: AnyDhtHash) ->
     )
 }
 
-#[hdk_extern]
-pub fn get_zome_package_version(addr: EntityId) -> ExternResult<Entity<ZomePackageVersionEntry>> {
+/// Get the latest state of a version, following group content updates
+pub fn get_zome_package_version_entity(addr: EntityId) -> ExternResult<Entity<ZomePackageVersionEntry>> {
     let addr = trace_origin_root( &addr )?.0;
     let record = must_get( &addr )?;
     let zome_package_entry = ZomePackageVersionEntry::try_from_record( &record )?;
@@ -168,12 +271,31 @@ pub fn get_zome_package_version(addr: EntityId) -> ExternResult<Entity<ZomePacka
 }
 
 
+#[hdk_extern]
+pub fn get_zome_package_version(addr: EntityId) ->
+    ExternResult<AttestedVersion<ZomePackageVersionEntry>>
+{
+    let version = get_zome_package_version_entity( addr )?;
+    let matching_rebuild_attestations = count_matching_rebuild_attestations(
+        &version.id, &version.content,
+    )?;
+
+    Ok(
+        AttestedVersion {
+            version,
+            matching_rebuild_attestations,
+        }
+    )
+}
+
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct UpdateProperties {
     pub maintainer: Option<Authority>,
     pub readme: Option<EntryHash>,
     pub changelog: Option<EntryHash>,
     pub source_code_revision_uri: Option<String>,
+    pub build_provenance: Option<BuildProvenance>,
     pub api_compatibility: Option<ApiCompatibility>,
     pub metadata: Option<BTreeMap<String, RmpvValue>>,
 }
@@ -195,6 +317,8 @@ pub fn update_zome_package_version(input: UpdateInput) -> ExternResult<Entity<Zo
 		.or( current.changelog );
 	    current.source_code_revision_uri = props.source_code_revision_uri
 		.or( current.source_code_revision_uri );
+	    current.build_provenance = props.build_provenance
+		.or( current.build_provenance );
 	    current.api_compatibility = props.api_compatibility
 		.unwrap_or( current.api_compatibility );
 	    current.metadata = props.metadata
@@ -207,12 +331,209 @@ pub fn update_zome_package_version(input: UpdateInput) -> ExternResult<Entity<Zo
 }
 
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct YankZomePackageVersionInput {
+    pub reason: String,
+    pub recommended_version: Option<String>,
+    pub maintainer: Option<Authority>,
+}
+
+#[hdk_extern]
+pub fn yank_zome_package_version(input: UpdateEntityInput<YankZomePackageVersionInput>) ->
+    ExternResult<Entity<ZomePackageVersionEntry>>
+{
+    let props = input.properties;
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : ZomePackageVersionEntry, _| {
+	    if current.yanked.is_some() {
+		return Err(guest_error!(format!(
+		    "Zome package version ({}) is already yanked", input.base,
+		)));
+	    }
+
+	    current.yanked = Some(YankNotice {
+		reason: props.reason.clone(),
+		recommended_version: props.recommended_version.clone(),
+	    });
+	    current.maintainer = props.maintainer.clone()
+		.unwrap_or( current.maintainer );
+
+	    Ok( current )
+	})?;
+
+    Ok( entity )
+}
+
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UnyankZomePackageVersionInput {
+    pub maintainer: Option<Authority>,
+}
+
+#[hdk_extern]
+pub fn unyank_zome_package_version(input: UpdateEntityInput<UnyankZomePackageVersionInput>) ->
+    ExternResult<Entity<ZomePackageVersionEntry>>
+{
+    let props = input.properties;
+
+    let entity = update_entity(
+	&input.base,
+	|mut current : ZomePackageVersionEntry, _| {
+	    if current.yanked.is_none() {
+		return Err(guest_error!(format!(
+		    "Zome package version ({}) is not yanked", input.base,
+		)));
+	    }
+
+	    current.yanked = None;
+	    current.maintainer = props.maintainer.clone()
+		.unwrap_or( current.maintainer );
+
+	    Ok( current )
+	})?;
+
+    Ok( entity )
+}
+
+
 #[hdk_extern]
 pub fn delete_zome_package_version(id: EntityId) -> ExternResult<bool> {
-    let package_version = get_zome_package_version( id.clone() )?.content;
+    let package_version = get_zome_package_version_entity( id.clone() )?.content;
     let zome_base = ZomePackageBase::new( &package_version.for_package );
 
     zome_base.version_link_base().delete_all_my_links_to_target( &id, None )?;
 
     Ok(true)
 }
+
+
+/// Walk the dependencies of a zome package version transitively
+///
+/// The result is flattened to 1 entry per package.  It is an error for 2 dependents to pin
+/// different versions of the same package, or for a pinned version to not satisfy its
+/// requirement.
+#[hdk_extern]
+pub fn resolve_zome_dependency_tree(version_id: EntityId) ->
+    ExternResult<Vec<ResolvedZomeDependency>>
+{
+    let root = get_zome_package_version_entity( version_id )?;
+    let mut resolved : BTreeMap<EntityId, ResolvedZomeDependency> = BTreeMap::new();
+    let mut queue : VecDeque<ZomeDependency> = root.content.dependencies
+        .unwrap_or_default().into();
+
+    while let Some(dependency) = queue.pop_front() {
+        let requirement = VersionReq::parse( &dependency.version_req )
+            .map_err( |err| guest_error!(format!(
+                "Invalid semver requirement '{}' for dependency '{}': {}",
+                dependency.version_req, dependency.name, err,
+            )) )?;
+
+        // Legacy dependencies did not pin a version ID so the highest non-yanked matching version
+        // is used
+        let version_id = match dependency.version.clone() {
+            Some(version_id) => version_id,
+            None => ZomePackageBase::new( &dependency.package ).latest_version_matching( &requirement )?
+                .map( |(_, version)| version.id )
+                .ok_or(guest_error!(format!(
+                    "No available version of package ({}) satisfies requirement '{}' for dependency '{}'",
+                    dependency.package, dependency.version_req, dependency.name,
+                )))?,
+        };
+
+        // A cycle back to the root is fine as long as it pins the root version
+        if dependency.package == root.content.for_package {
+            if version_id != root.id {
+                return Err(guest_error!(format!(
+                    "Dependency '{}' pins version ({}) of the root package but the root version is ({})",
+                    dependency.name, version_id, root.id,
+                )));
+            }
+            continue;
+        }
+
+        if let Some(existing) = resolved.get( &dependency.package ) {
+            if existing.version_id != version_id {
+                return Err(guest_error!(format!(
+                    "Conflicting versions of package ({}) for dependency '{}': {} != {}",
+                    dependency.package, dependency.name, existing.version_id, version_id,
+                )));
+            }
+            continue;
+        }
+
+        if let Some(existing) = resolved.values().find( |existing| existing.name == dependency.name ) {
+            return Err(guest_error!(format!(
+                "Dependency name '{}' is used by more than 1 package: {} != {}",
+                dependency.name, existing.package, dependency.package,
+            )));
+        }
+
+        let version = get_zome_package_version_entity( version_id.clone() )?;
+        let vtag = ZomePackageBase::new( &dependency.package ).version_targets()?.into_iter()
+            .find_map( |(vtag, id)| match id == version_id {
+                true => Some( vtag ),
+                false => None,
+            })
+            .ok_or(guest_error!(format!(
+                "Pinned version ({}) of dependency '{}' is not linked from package ({})",
+                version_id, dependency.name, dependency.package,
+            )))?;
+
+        let semver = Version::parse( &vtag )
+            .map_err( |err| guest_error!(format!(
+                "Pinned version '{}' of dependency '{}' is not a valid semver: {}",
+                vtag, dependency.name, err,
+            )) )?;
+
+        if !requirement.matches( &semver ) {
+            return Err(guest_error!(format!(
+                "Pinned version '{}' of dependency '{}' does not satisfy requirement '{}'",
+                vtag, dependency.name, dependency.version_req,
+            )));
+        }
+
+        let zome_entry : ZomeEntry = must_get( &version.content.zome_entry )?.try_into()?;
+
+        queue.extend( version.content.dependencies.clone().unwrap_or_default() );
+
+        resolved.insert( dependency.package.clone(), ResolvedZomeDependency {
+            name: dependency.name,
+            package: dependency.package,
+            version: vtag,
+            version_id,
+            zome_type: zome_entry.zome_type,
+            zome_entry: version.content.zome_entry,
+            hash: zome_entry.hash,
+            yanked: version.content.yanked,
+        });
+    }
+
+    Ok( resolved.into_values().collect() )
+}
+
+
+/// Get the zome package versions that depend on the given version
+///
+/// Combined with `get_dependents` in dnahub and apphub (via `call_cell`), this answers which DNAs
+/// and apps are affected by a zome package version.
+#[hdk_extern]
+pub fn get_dependents(version_id: EntityId) ->
+    ExternResult<Vec<Entity<ZomePackageVersionEntry>>>
+{
+    let base = LinkBase::new( version_id, LinkTypes::ZomePackageVersionToDependent );
+
+    let mut seen = std::collections::BTreeSet::new();
+    let dependents = base.get_links( None )?.into_iter()
+        .filter_map(|link| {
+            let addr = link.target.into_action_hash()?;
+            match seen.insert( addr.clone() ) {
+                true => get_zome_package_version_entity( addr ).ok(),
+                false => None,
+            }
+        })
+        .collect();
+
+    Ok( dependents )
+}